@@ -0,0 +1,36 @@
+#![feature(portable_simd, test)]
+
+extern crate test;
+
+use core_simd::simd::{f64x4, num::SimdFloat};
+use std_float::{sum_of_products, StdFloat};
+use test::{black_box, Bencher};
+
+const LEN: usize = 4096;
+
+fn data() -> (Vec<f64x4>, Vec<f64x4>) {
+    let a = (0..LEN).map(|i| f64x4::splat(i as f64).sqrt()).collect();
+    let b = (0..LEN)
+        .map(|i| f64x4::splat(1.0 / (i + 1) as f64))
+        .collect();
+    (a, b)
+}
+
+#[bench]
+fn compensated(bench: &mut Bencher) {
+    let (a, b) = data();
+    bench.iter(|| sum_of_products(black_box(&a), black_box(&b)));
+}
+
+/// The uncompensated sum, for comparison.
+#[bench]
+fn naive(bench: &mut Bencher) {
+    let (a, b) = data();
+    bench.iter(|| {
+        let (a, b) = (black_box(&a), black_box(&b));
+        a.iter()
+            .zip(b)
+            .fold(f64x4::splat(0.0), |sum, (&x, &y)| x.mul_add(y, sum))
+            .reduce_sum()
+    });
+}
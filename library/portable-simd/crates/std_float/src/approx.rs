@@ -0,0 +1,266 @@
+//! Coefficients of the minimax polynomials behind the `*_approx` methods.
+//!
+//! Each polynomial minimizes the maximum relative error over its interval. The coefficients are
+//! listed lowest order first and are rounded to `f64`; the `f32` implementations round them
+//! again to `f32`.
+
+/// The difference between `ln(2)` and `LN_2`, for extended-precision range reduction.
+pub(crate) const LN_2_LO: f64 = 2.3190468138462996e-17;
+
+/// The difference between `π` and `PI`, for extended-precision range reduction.
+pub(crate) const PI_LO: f64 = 1.2246467991473532e-16;
+
+/// The difference between `π` and `PI + PI_LO`, for range reduction that needs a third part.
+pub(crate) const PI_LO_2: f64 = -2.9947698097183397e-33;
+
+/// Coefficients of `exp(r)` for `r` in `[-ln(2) / 2, ln(2) / 2]`, by degree.
+pub(crate) const EXP: [&[f64]; 11] = [
+    // Degree 1.
+    &[1.0290300065042215, 0.9897176582063855],
+    // Degree 2.
+    &[1.0004431419562678, 1.014860949622588, 0.49625859107344905],
+    // Degree 3.
+    &[
+        0.9999280735393952,
+        1.0001641857658374,
+        0.5049632641799252,
+        0.16566842342912194,
+    ],
+    // Degree 4.
+    &[
+        0.9999992614457125,
+        0.9999634048525794,
+        0.5000435866128775,
+        0.16790907215254922,
+        0.04145860820106741,
+    ],
+    // Degree 5.
+    &[
+        1.0000000716546849,
+        0.999999691991555,
+        0.4999889485120397,
+        0.16667574728621964,
+        0.04191538199275342,
+        0.008297655088545722,
+    ],
+    // Degree 6.
+    &[
+        1.0000000005541665,
+        1.0000000363231987,
+        0.499999920798166,
+        0.1666642016984746,
+        0.04166822556948865,
+        0.008374815804468408,
+        0.0013836845994063783,
+    ],
+    // Degree 7.
+    &[
+        0.9999999999616819,
+        1.0000000002430967,
+        0.500000010453624,
+        0.16666665126137534,
+        0.04166622542544879,
+        0.008333561090187073,
+        0.001394818333004356,
+        0.00019775171607824834,
+    ],
+    // Degree 8.
+    &[
+        0.9999999999997623,
+        0.9999999999806229,
+        0.5000000000618229,
+        0.16666666885447928,
+        0.04166666422004523,
+        0.008333267031838178,
+        0.001388917864511218,
+        0.00019915422067675253,
+        2.4727215914716094e-05,
+    ],
+    // Degree 9.
+    &[
+        1.0000000000000127,
+        0.9999999999998928,
+        0.4999999999945797,
+        0.16666666667826951,
+        0.04166666703246359,
+        0.008333333002823906,
+        0.0013888802828792976,
+        0.00019841596395523973,
+        2.488401002622006e-05,
+        2.7482187570947232e-06,
+    ],
+    // Degree 10.
+    &[
+        1.0,
+        1.0000000000000064,
+        0.49999999999997286,
+        0.16666666666557742,
+        0.04166666666842604,
+        0.00833333338466586,
+        0.0013888888499132617,
+        0.00019841171384145565,
+        2.4801917693499152e-05,
+        2.763976828264541e-06,
+        2.748844159545267e-07,
+    ],
+    // Degree 11.
+    &[
+        1.0,
+        1.0,
+        0.5000000000000018,
+        0.1666666666666617,
+        0.04166666666649277,
+        0.008333333333559272,
+        0.0013888888951224037,
+        0.0001984126943267626,
+        2.4801486521375963e-05,
+        2.755762253355922e-06,
+        2.763229329749704e-07,
+        2.4994304016107913e-08,
+    ],
+];
+
+/// Coefficients of `sin(r) / r` as a polynomial in `r * r`, for `r` in `[-π / 2, π / 2]`, by
+/// degree of the resulting polynomial in `r`.
+pub(crate) const SIN: [&[f64]; 8] = [
+    // Degree 3.
+    &[0.9927877289831643, -0.14621029021538304],
+    // Degree 5.
+    &[
+        0.9998918212558109,
+        -0.165960116540879,
+        0.0076029033433693514,
+    ],
+    // Degree 7.
+    &[
+        0.9999990608989764,
+        -0.16665554092757692,
+        0.00831189980138988,
+        -0.0001848814028860719,
+    ],
+    // Degree 9.
+    &[
+        0.9999999946860073,
+        -0.1666665668400715,
+        0.008333025138969368,
+        -0.0001980741872742697,
+        2.60190306765146e-06,
+    ],
+    // Degree 11.
+    &[
+        0.999999999978849,
+        -0.1666666660882607,
+        0.008333330720557737,
+        -0.00019840832823261957,
+        2.752397107463265e-06,
+        -2.3868346521031026e-08,
+    ],
+    // Degree 13.
+    &[
+        0.9999999999999376,
+        -0.1666666666643233,
+        0.008333333318765514,
+        -0.0001984126641162215,
+        2.755693192659491e-06,
+        -2.5029518865603207e-08,
+        1.5401170371414643e-10,
+    ],
+    // Degree 15.
+    &[
+        0.9999999999999999,
+        -0.16666666666665966,
+        0.00833333333327592,
+        -0.0001984126982322251,
+        2.7557316421292966e-06,
+        -2.5051870883490903e-08,
+        1.6047844633018115e-10,
+        -7.370662782816782e-13,
+    ],
+    // Degree 17.
+    &[
+        1.0,
+        -0.16666666666666666,
+        0.008333333333333165,
+        -0.0001984126984120184,
+        2.7557319210152756e-06,
+        -2.5052106798274616e-08,
+        1.6058936490373223e-10,
+        -7.642917806936944e-13,
+        2.720479096311349e-15,
+    ],
+];
+
+/// Coefficients of `atanh(s) / s` as a polynomial in `s * s`, for `s` in
+/// `[-(3 - 2√2), 3 - 2√2]`, by degree of the resulting polynomial in `s`.
+pub(crate) const LN: [&[f64]; 7] = [
+    // Degree 3.
+    &[0.9999777446767659, 0.3393399287932016],
+    // Degree 5.
+    &[1.0000001186870144, 0.3332611185005319, 0.2064818643257653],
+    // Degree 7.
+    &[
+        0.999999999310665,
+        0.33333407975427853,
+        0.19987397462394205,
+        0.14962825350883843,
+    ],
+    // Degree 9.
+    &[
+        1.000000000004212,
+        0.3333333262022641,
+        0.2000019293453569,
+        0.14267492963670117,
+        0.1180873291399262,
+    ],
+    // Degree 11.
+    &[
+        0.9999999999999734,
+        0.33333333339825727,
+        0.19999997436090733,
+        0.1428608400845788,
+        0.11087088607269767,
+        0.09804542013731705,
+    ],
+    // Degree 13.
+    &[
+        1.0000000000000002,
+        0.3333333333327611,
+        0.2000000003100823,
+        0.14285707990616114,
+        0.11111718580962976,
+        0.09060927719223207,
+        0.0841927744589367,
+    ],
+    // Degree 15.
+    &[
+        1.0,
+        0.33333333333333826,
+        0.1999999999964944,
+        0.14285714380673384,
+        0.11111098494120161,
+        0.0909181754725133,
+        0.0765622187650561,
+        0.07405264921553475,
+    ],
+];
+
+/// The kernels of fdlibm, minimax on `[-π / 4, π / 4]`: the coefficients of `sin(r)` from the
+/// cubic term and of `cos(r)` from the quartic term, as polynomials in `r * r`.
+pub(crate) const KERNEL_SIN: [f64; 6] = [
+    -0.16666666666666632,
+    0.00833333333332249,
+    -0.0001984126982985795,
+    2.7557313707070068e-06,
+    -2.5050760253406863e-08,
+    1.58969099521155e-10,
+];
+
+/// See [`KERNEL_SIN`].
+pub(crate) const KERNEL_COS: [f64; 6] = [
+    0.0416666666666666,
+    -0.001388888888887411,
+    2.480158728947673e-05,
+    -2.7557314351390663e-07,
+    2.087572321298175e-09,
+    -1.1359647557788195e-11,
+];
@@ -0,0 +1,54 @@
+//! Color blending.
+
+use super::{
+    simd::{
+        cmp::{SimdPartialEq, SimdPartialOrd},
+        Mask, Simd,
+    },
+    StdFloat,
+};
+
+/// Blends the sRGB colors `a` and `b`, weighting `b` by `t`, in linear light.
+///
+/// The first three lanes hold the red, green and blue channels in the sRGB encoding. They are
+/// decoded with the sRGB transfer function, interpolated in linear light, and encoded again,
+/// which keeps the brightness of the midtones that interpolating the encoded values directly
+/// would darken. The fourth lane holds alpha, which is already linear and is interpolated
+/// directly.
+///
+/// Each lane of `t` weights the matching channel. Where `t` is `0.0` or `1.0`, the channel of
+/// `a` or `b` is returned exactly.
+#[inline]
+#[must_use]
+pub fn mix_srgb(a: Simd<f32, 4>, b: Simd<f32, 4>, t: Simd<f32, 4>) -> Simd<f32, 4> {
+    let lerp = |a: Simd<f32, 4>, b: Simd<f32, 4>| t.mul_add(b - a, a);
+    let color = Mask::<i32, 4>::from_array([true, true, true, false]);
+    let mixed = color.select(
+        linear_to_srgb(lerp(srgb_to_linear(a), srgb_to_linear(b))),
+        lerp(a, b),
+    );
+    let mixed = t.simd_eq(Simd::splat(0.0)).select(a, mixed);
+    t.simd_eq(Simd::splat(1.0)).select(b, mixed)
+}
+
+/// Decodes sRGB encoded channels to linear light.
+#[inline]
+fn srgb_to_linear(c: Simd<f32, 4>) -> Simd<f32, 4> {
+    let low = c / Simd::splat(12.92);
+    let high = pow((c + Simd::splat(0.055)) / Simd::splat(1.055), 2.4);
+    c.simd_le(Simd::splat(0.04045)).select(low, high)
+}
+
+/// Encodes linear light channels with the sRGB transfer function.
+#[inline]
+fn linear_to_srgb(l: Simd<f32, 4>) -> Simd<f32, 4> {
+    let low = l * Simd::splat(12.92);
+    let high = Simd::splat(1.055).mul_add(pow(l, 1.0 / 2.4), Simd::splat(-0.055));
+    l.simd_le(Simd::splat(0.0031308)).select(low, high)
+}
+
+/// Raises each of the non-negative elements of `x` to the power `exp`.
+#[inline]
+fn pow(x: Simd<f32, 4>, exp: f32) -> Simd<f32, 4> {
+    (x.log2() * Simd::splat(exp)).exp2()
+}
@@ -0,0 +1,94 @@
+//! Arithmetic on complex numbers stored interleaved in a vector.
+//!
+//! A vector of `N` lanes holds `N / 2` complex numbers, each as a real part in an even lane
+//! followed by its imaginary part in the next lane. `N` must be even; using these functions with
+//! an odd `N` fails to compile.
+
+use super::{
+    simd::{LaneCount, Mask, Simd, SupportedLaneCount, Swizzle},
+    StdFloat,
+};
+
+/// Multiplies the complex numbers in `a` by those in `b`, pair by pair.
+///
+/// Each product is `(a.re * b.re - a.im * b.im, a.re * b.im + a.im * b.re)`, with the second
+/// product of each part fused into the first with a multiply-add.
+#[inline]
+#[must_use]
+pub fn complex_mul<const N: usize>(a: Simd<f32, N>, b: Simd<f32, N>) -> Simd<f32, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    let re = Pair::<0>::swizzle(a);
+    let im = Pair::<1>::swizzle(a);
+    let swapped = Swapped::swizzle(b);
+    // Negating the real lanes by multiplying with a sign is exact.
+    let sign = Simd::from_array(core::array::from_fn(
+        |i| if i % 2 == 0 { -1.0 } else { 1.0 },
+    ));
+    (im * sign).mul_add(swapped, re * b)
+}
+
+/// Converts complex numbers from polar form, with moduli in `r` and arguments in `theta`, to
+/// interleaved real and imaginary parts.
+///
+/// `r` and `theta` are laid out like the result, so complex number `k` takes its modulus and
+/// argument from lane `2 * k` of each, and lane `2 * k + 1` is ignored. The parts are
+/// `r * cos(theta)` and `r * sin(theta)`, computed with [`sin_cos`](StdFloat::sin_cos).
+#[inline]
+#[must_use]
+pub fn polar_to_complex<const N: usize>(r: Simd<f32, N>, theta: Simd<f32, N>) -> Simd<f32, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    let r = Pair::<0>::swizzle(r);
+    let (sin, cos) = Pair::<0>::swizzle(theta).sin_cos();
+    let real = Mask::<i32, N>::from_array(core::array::from_fn(|i| i % 2 == 0));
+    r * real.select(cos, sin)
+}
+
+/// Computes the magnitude of each complex number, in both of its lanes.
+///
+/// The magnitude is computed with [`hypot`](StdFloat::hypot), so it does not overflow or
+/// underflow unless the result does.
+#[inline]
+#[must_use]
+pub fn complex_abs<const N: usize>(z: Simd<f32, N>) -> Simd<f32, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    Pair::<0>::swizzle(z).hypot(Pair::<1>::swizzle(z))
+}
+
+/// Copies the real (`PART = 0`) or imaginary (`PART = 1`) part of each complex number into both
+/// of its lanes.
+struct Pair<const PART: usize>;
+
+impl<const PART: usize, const N: usize> Swizzle<N> for Pair<PART> {
+    const INDEX: [usize; N] = {
+        assert!(N % 2 == 0, "complex numbers take an even number of lanes");
+        let mut index = [0; N];
+        let mut i = 0;
+        while i < N {
+            index[i] = i - i % 2 + PART;
+            i += 1;
+        }
+        index
+    };
+}
+
+/// Swaps the real and imaginary parts of each complex number.
+struct Swapped;
+
+impl<const N: usize> Swizzle<N> for Swapped {
+    const INDEX: [usize; N] = {
+        assert!(N % 2 == 0, "complex numbers take an even number of lanes");
+        let mut index = [0; N];
+        let mut i = 0;
+        while i < N {
+            index[i] = i ^ 1;
+            i += 1;
+        }
+        index
+    };
+}
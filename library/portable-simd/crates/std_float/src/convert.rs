@@ -0,0 +1,135 @@
+//! Conversions from integer vectors to float vectors, and between `f32` and half precision.
+//!
+//! The integer conversions are the same as an `as` cast on each element, so an integer that
+//! cannot be represented exactly is rounded to the nearest representable float, with ties
+//! rounding to the float with an even mantissa. No conversion can overflow, as the largest
+//! integer of each type is smaller than the largest finite float of the matching width.
+//!
+//! `Simd` does not support `f16` elements, so half precision values are passed around as the
+//! bits of their IEEE 754 binary16 encoding, in a `u16` vector.
+
+use super::simd::{
+    cmp::{SimdPartialEq, SimdPartialOrd},
+    num::{SimdFloat, SimdInt, SimdUint},
+    LaneCount, Simd, SupportedLaneCount,
+};
+
+macro_rules! impl_from_int {
+    { $($(#[$attr:meta])* $name:ident: $int:ty => $float:ty,)* } => {
+        $(
+        $(#[$attr])*
+        #[inline]
+        #[must_use]
+        pub fn $name<const N: usize>(v: Simd<$int, N>) -> Simd<$float, N>
+        where
+            LaneCount<N>: SupportedLaneCount,
+        {
+            v.cast()
+        }
+        )*
+    }
+}
+
+impl_from_int! {
+    /// Converts each element of `v` to the nearest `f32`.
+    ///
+    /// Values with a magnitude above `2^24` may be rounded.
+    from_i32: i32 => f32,
+    /// Converts each element of `v` to the nearest `f32`.
+    ///
+    /// Values above `2^24` may be rounded.
+    from_u32: u32 => f32,
+    /// Converts each element of `v` to the nearest `f64`.
+    ///
+    /// Values with a magnitude above `2^53` may be rounded.
+    from_i64: i64 => f64,
+    /// Converts each element of `v` to the nearest `f64`.
+    ///
+    /// Values above `2^53` may be rounded.
+    from_u64: u64 => f64,
+}
+
+/// Converts each element of `v` to the nearest half precision float, returning its bits.
+///
+/// Ties round to the value with an even mantissa, as with an `as` cast to `f16`. Values too small
+/// for a normal half precision float are rounded to a subnormal one or to zero, keeping the sign.
+/// Values of a magnitude of `65520.0` and above, which would round past the largest finite half
+/// precision float, overflow to infinity. A `NaN` is converted to a quiet `NaN` with the same
+/// sign, but its payload is not kept.
+#[inline]
+#[must_use]
+pub fn to_f16_bits<const N: usize>(v: Simd<f32, N>) -> Simd<u16, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    let bits = v.to_bits();
+    let sign = bits & Simd::splat(0x8000_0000);
+    let abs = bits ^ sign;
+
+    // Below the smallest normal half precision float, `2^-14`, adding `0.5` leaves the result in
+    // units of the smallest subnormal, `2^-24`, in the mantissa of the sum, rounded to nearest
+    // even by the addition itself.
+    let magic = Simd::<f32, N>::splat(0.5);
+    let subnormal = (Simd::from_bits(abs) + magic).to_bits() - magic.to_bits();
+
+    // Otherwise, rebias the exponent and round away the low 13 bits of the mantissa. A carry out
+    // of the mantissa correctly increments the exponent, up to infinity.
+    let odd = (abs >> 13) & Simd::splat(1);
+    let rebias = Simd::splat(((15 - 127) << 23) as u32 + 0xfff);
+    let normal = (abs + rebias + odd) >> 13;
+
+    let min_normal = Simd::splat(113 << 23);
+    let overflow = Simd::splat((127 + 16) << 23);
+    let infinity = Simd::splat(0x7f80_0000);
+    let half = abs.simd_lt(min_normal).select(subnormal, normal);
+    let half = abs.simd_gt(infinity).select(Simd::splat(0x7e00), half);
+    let half = (abs.simd_ge(overflow) & abs.simd_le(infinity)).select(Simd::splat(0x7c00), half);
+    (half | (sign >> 16)).cast()
+}
+
+/// Converts each element of `v`, the bits of a half precision float, to an `f32`.
+///
+/// Every half precision float is exactly representable as an `f32`, so this never rounds. A
+/// `NaN` keeps its sign and payload.
+#[inline]
+#[must_use]
+pub fn from_f16_bits<const N: usize>(v: Simd<u16, N>) -> Simd<f32, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    let bits: Simd<u32, N> = v.cast();
+    let exp_mask = Simd::splat(0x7c00 << 13);
+    let shifted = (bits & Simd::splat(0x7fff)) << 13;
+    let exp = shifted & exp_mask;
+    let rebiased = shifted + Simd::splat((127 - 15) << 23);
+
+    // Infinities and `NaN`s take the largest exponent.
+    let inf_nan = rebiased + Simd::splat((128 - 16) << 23);
+    // Zeros and subnormals are renormalized by giving them the smallest normal exponent, then
+    // subtracting the smallest normal float that this added.
+    let min_normal = Simd::<f32, N>::splat(f32::from_bits(113 << 23));
+    let subnormal = (Simd::from_bits(rebiased + Simd::splat(1 << 23)) - min_normal).to_bits();
+
+    let abs = exp.simd_eq(exp_mask).select(inf_nan, rebiased);
+    let abs = exp.simd_eq(Simd::splat(0)).select(subnormal, abs);
+    Simd::from_bits(abs | ((bits & Simd::splat(0x8000)) << 16))
+}
+
+/// Applies `f` to the half precision floats whose bits are the elements of `v`, by widening them
+/// to `f32`, and returns the bits of the results rounded back to half precision.
+///
+/// This is how the [`StdFloat`](crate::StdFloat) methods apply to half precision vectors, such as
+/// `map_f16_bits(v, StdFloat::sin)`, until `Simd` supports `f16` elements. The result of `f` is
+/// rounded once, so if it is within half a unit in the last place of `f32`, the result is within
+/// a unit in the last place of half precision, and usually correctly rounded.
+#[inline]
+#[must_use]
+pub fn map_f16_bits<const N: usize>(
+    v: Simd<u16, N>,
+    f: impl FnOnce(Simd<f32, N>) -> Simd<f32, N>,
+) -> Simd<u16, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    to_f16_bits(f(from_f16_bits(v)))
+}
@@ -0,0 +1,172 @@
+//! Sine and cosine that have the same bits on every target.
+//!
+//! Both element types are evaluated in `f64` with correctly rounded operations only: addition,
+//! multiplication, fused multiply-add, rounding to an integer and conversions. Elements up to
+//! `2^20 * π / 2` are reduced with `π / 2` split into three parts, and larger ones one at a time
+//! by the method of Payne and Hanek, in integer arithmetic against the bits of `2 / π`.
+
+use super::{
+    approx,
+    simd::{
+        cmp::{SimdPartialEq, SimdPartialOrd},
+        num::SimdFloat,
+        LaneCount, Simd, SupportedLaneCount,
+    },
+    StdFloat,
+};
+use core::f64::consts::{FRAC_2_PI, FRAC_PI_2};
+
+/// The bits of `2 / π` after the point, enough for the reduction of the largest `f64`.
+const TWO_OVER_PI: [u64; 19] = [
+    0xA2F9836E4E441529,
+    0xFC2757D1F534DDC0,
+    0xDB6295993C439041,
+    0xFE5163ABDEBBC561,
+    0xB7246E3A424DD2E0,
+    0x06492EEA09D1921C,
+    0xFE1DEB1CB129A73E,
+    0xE88235F52EBB4484,
+    0xE99C7026B45F7E41,
+    0x3991D639835339F4,
+    0x9C845F8BBDF9283B,
+    0x1FF897FFDE05980F,
+    0xEF2F118B5A0A6D1F,
+    0x6D367ECF27CB09B7,
+    0x4F463F669E5FEA2D,
+    0x7527BAC7EBE5F17B,
+    0x3D0739F78A5292EA,
+    0x6BFB5FB11F8D5D08,
+    0x56033046FC7B6BAB,
+];
+
+/// The second and third parts of `π / 2`, after `FRAC_PI_2`.
+const PIO2_2: f64 = approx::PI_LO / 2.0;
+const PIO2_3: f64 = approx::PI_LO_2 / 2.0;
+
+/// The largest multiple of `π / 2` reduced in three parts. Past it, the error of the parts
+/// times the multiple is no longer negligible next to the closest an `f64` comes to a multiple.
+const MAX_K: f64 = (1 << 20) as f64;
+
+/// Returns the 64 bits of `2 / π` from bit `pos` after the point, counting from 1, with zeros
+/// before the point.
+fn two_over_pi_bits(pos: i32) -> u64 {
+    if pos < 1 {
+        let shift = 1 - pos;
+        if shift < 64 {
+            two_over_pi_bits(1) >> shift
+        } else {
+            0
+        }
+    } else {
+        let (word, shift) = (((pos - 1) / 64) as usize, (pos - 1) % 64);
+        let high = TWO_OVER_PI[word] << shift;
+        if shift == 0 {
+            high
+        } else {
+            high | TWO_OVER_PI[word + 1] >> (64 - shift)
+        }
+    }
+}
+
+/// Reduces a finite `x` with `|x| >= 2^20` to `x = q * π / 2 + (hi + lo)` with
+/// `|hi + lo| <= π / 4`, returning `q` modulo 4, `hi` and `lo`.
+fn rem_pio2_large(x: f64) -> (i32, f64, f64) {
+    // `x = m * 2^e` with an integer `m`. Bits of `2 / π` worth 4 or more once multiplied by
+    // `2^e` only add multiples of 4 to `x * 2 / π`, so the product starts one bit before those.
+    let bits = x.to_bits();
+    let e = ((bits >> 52) & 0x7ff) as i32 - 1075;
+    let m = ((bits & ((1 << 52) - 1)) | (1 << 52)) as u128;
+    let start = e - 1;
+    let w = [0, 64, 128].map(|i| two_over_pi_bits(start + i) as u128);
+
+    // The low 192 bits of `m * w`, which is `x * 2 / π` with 2 bits before the point.
+    let low = m * w[2];
+    let mid = m * w[1] + (low >> 64);
+    let high = (m * w[0] + (mid >> 64)) as u64;
+    let q = (high >> 62) as i32;
+    let fraction = ((high as u128) << 64 | mid as u64 as u128) << 2 | (low as u64 >> 62) as u128;
+
+    // Round to the nearest quadrant, leaving a signed fraction of `2^128`.
+    let f = fraction as i128;
+    let q = q + (f < 0) as i32;
+    let f_hi = f as f64;
+    let f_lo = (f - f_hi as i128) as f64;
+    let scale = 1.0 / (1u128 << 64) as f64 / (1u128 << 64) as f64;
+    let (f_hi, f_lo) = (f_hi * scale, f_lo * scale);
+
+    // Times `π / 2`, in two parts.
+    let hi = f_hi * FRAC_PI_2;
+    let lo = scalar_fn!(f64::mul_add | fma)(f_hi, FRAC_PI_2, -hi);
+    let lo = lo + (f_hi * PIO2_2 + f_lo * FRAC_PI_2);
+    let (r, q) = (hi + lo, q & 3);
+    let lo = lo - (r - hi);
+    if x < 0.0 {
+        (-q & 3, -r, -lo)
+    } else {
+        (q, r, lo)
+    }
+}
+
+/// Returns the sine and the cosine of each element.
+#[inline]
+pub(crate) fn sin_cos<const N: usize>(x: Simd<f64, N>) -> (Simd<f64, N>, Simd<f64, N>)
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    // Reduce to `x = k * π / 2 + (hi + lo)`. The first step is exact, and the product with the
+    // second part is split exactly so that only the third part is rounded.
+    let k = (x * Simd::splat(FRAC_2_PI)).round();
+    let r = (-k).mul_add(Simd::splat(FRAC_PI_2), x);
+    let w = k * Simd::splat(PIO2_2);
+    let w_lo = k.mul_add(Simd::splat(PIO2_2), -w);
+    let hi = r - w;
+    let b = hi - r;
+    let lo = (r - (hi - b)) - (w + b);
+    let lo = lo - w_lo - k * Simd::splat(PIO2_3);
+    let r = hi + lo;
+    let (mut hi, mut lo) = (r, lo - (r - hi));
+    let mut q = k.cast::<i32>();
+
+    let large = SimdFloat::abs(k).simd_gt(Simd::splat(MAX_K)) & x.is_finite();
+    if large.any() {
+        let (mut q_a, mut hi_a, mut lo_a) = (q.to_array(), hi.to_array(), lo.to_array());
+        for i in 0..N {
+            if large.test(i) {
+                (q_a[i], hi_a[i], lo_a[i]) = rem_pio2_large(x[i]);
+            }
+        }
+        (q, hi, lo) = (
+            Simd::from_array(q_a),
+            Simd::from_array(hi_a),
+            Simd::from_array(lo_a),
+        );
+    }
+
+    // The kernels of fdlibm, with the correction for `lo`.
+    let (s, c) = (&approx::KERNEL_SIN, &approx::KERNEL_COS);
+    let z = hi * hi;
+    let v = z * hi;
+    let half = Simd::splat(0.5);
+    let mut ps = Simd::splat(0.0);
+    for &coeff in s[1..].iter().rev() {
+        ps = ps.mul_add(z, Simd::splat(coeff));
+    }
+    let s = hi - ((z * (half * lo - v * ps) - lo) - v * Simd::splat(s[0]));
+    let mut pc = Simd::splat(0.0);
+    for &coeff in c.iter().rev() {
+        pc = pc.mul_add(z, Simd::splat(coeff));
+    }
+    let half_z = half * z;
+    let one = Simd::splat(1.0);
+    let w = one - half_z;
+    let c = w + (((one - w) - half_z) + ((z * z) * pc - hi * lo));
+
+    // Rotate by the quadrant `q mod 4`.
+    let bit = |q: Simd<i32, N>, b: i32| (q & Simd::splat(b)).simd_ne(Simd::splat(0));
+    let (sin, cos) = (bit(q, 1).select(c, s), bit(q, 1).select(s, c));
+    let sin = bit(q, 2).select(-sin, sin);
+    let cos = bit(q + Simd::splat(1), 2).select(-cos, cos);
+    // The reduction turns `-0.0` into `0.0`.
+    let sin = x.simd_eq(Simd::splat(0.0)).select(x, sin);
+    (sin, cos)
+}
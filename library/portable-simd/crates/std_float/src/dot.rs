@@ -0,0 +1,57 @@
+//! Dot products that span many vectors.
+
+use super::{
+    simd::{LaneCount, Simd, SupportedLaneCount},
+    StdFloat,
+};
+
+/// Returns the dot product of `a` and `b`, treated as two long vectors of `a.len() * N`
+/// elements each.
+///
+/// This is the batched version of [`dot4`](crate::dot4), for dot products too long to fit in one
+/// vector. It uses the compensated dot product algorithm of Ogita, Rump and Oishi: the rounding
+/// error of every product is recovered exactly with [`mul_add`](StdFloat::mul_add), that of
+/// every sum with an error-free transformation, and the errors are accumulated alongside the
+/// sum. The lanes are then reduced in the same way. The result is as accurate as if it were
+/// computed in twice the precision and then rounded, so it stays accurate under cancellation
+/// that leaves the naive sum with no correct digits at all.
+///
+/// # Panics
+///
+/// Panics if `a` and `b` have different lengths.
+#[inline]
+#[must_use]
+pub fn sum_of_products<const N: usize>(a: &[Simd<f64, N>], b: &[Simd<f64, N>]) -> f64
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    assert_eq!(a.len(), b.len(), "slices of different lengths");
+    let (mut sum, mut err) = (Simd::splat(0.0), Simd::splat(0.0));
+    for (&x, &y) in a.iter().zip(b) {
+        let product = x * y;
+        let product_err = x.mul_add(y, -product);
+        let (s, sum_err) = two_sum(sum, product);
+        sum = s;
+        err += product_err + sum_err;
+    }
+
+    let (mut total, mut total_err) = (0.0, 0.0);
+    for (s, e) in sum.to_array().into_iter().zip(err.to_array()) {
+        let (t, sum_err) = two_sum(total, s);
+        total = t;
+        total_err += sum_err + e;
+    }
+    total + total_err
+}
+
+/// Returns the rounded sum `a + b` and its rounding error, which together are exactly `a + b`.
+#[inline]
+fn two_sum<T>(a: T, b: T) -> (T, T)
+where
+    T: Copy + core::ops::Add<Output = T> + core::ops::Sub<Output = T>,
+{
+    let s = a + b;
+    let a_part = s - b;
+    let b_part = s - a_part;
+    (s, (a - a_part) + (b - b_part))
+}
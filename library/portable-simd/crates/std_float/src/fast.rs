@@ -0,0 +1,171 @@
+//! Relaxed counterparts of some `StdFloat` methods, which give up the handling of special values
+//! and the order of operations for throughput.
+
+use super::{
+    approx, intrinsics,
+    recip::RecipEstimate,
+    simd::{
+        cmp::SimdPartialOrd,
+        num::{SimdFloat, SimdUint},
+        LaneCount, Simd, SupportedLaneCount,
+    },
+    Sealed, StdFloat,
+};
+
+/// Relaxed, "fast math" versions of some [`StdFloat`] methods.
+///
+/// These are kept apart from `StdFloat` so that its contract is never weakened: a method of this
+/// trait only promises its documented accuracy for well-behaved elements, meaning finite normal
+/// elements whose result is also finite and normal. For `NaN`s, infinities and subnormals, in
+/// either the elements or the results, the result is unspecified: it may be `NaN`, infinite,
+/// flushed to zero or just wrong, and it may change between versions and targets. Operations may
+/// also be contracted or reassociated, so results may differ between targets in the last few
+/// places.
+pub trait FastStdFloat: Sealed + Sized {
+    /// The type of each element.
+    type Scalar;
+
+    /// Computes `(self * a) + b`, fused into one rounding where the target has an `fma`
+    /// instruction and with two roundings otherwise, whichever is faster.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn mul_add_fast(self, a: Self, b: Self) -> Self;
+
+    /// Approximates the reciprocal of each element, like
+    /// [`recip_approx`](StdFloat::recip_approx) but without its handling of special values.
+    ///
+    /// The error is within 3 units in the last place.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn recip_fast(self) -> Self;
+
+    /// Approximates the exponential (base e) of each element, with the polynomial of
+    /// [`AccuracyMode::Fast`](crate::AccuracyMode::Fast) and without its handling of special
+    /// values or subnormal results.
+    ///
+    /// The error is within 64 units in the last place for `f32` and 1024 for `f64`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn exp_fast(self) -> Self;
+
+    /// Approximates the natural logarithm of each element, with the polynomial of
+    /// [`AccuracyMode::Fast`](crate::AccuracyMode::Fast) and without its handling of special
+    /// values, subnormals or elements that are not positive.
+    ///
+    /// The error is within 64 units in the last place for `f32` and 1024 for `f64`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn ln_fast(self) -> Self;
+
+    /// Returns the sum of the elements, added in whatever order is fastest.
+    ///
+    /// The result may differ from [`reduce_sum`](SimdFloat::reduce_sum), which adds the elements
+    /// in order, by the rounding errors of the reordered additions.
+    #[must_use = "method returns a new value and does not mutate the original value"]
+    fn reduce_sum_fast(self) -> Self::Scalar;
+
+    /// Returns the product of the elements, multiplied in whatever order is fastest.
+    ///
+    /// The result may differ from [`reduce_product`](SimdFloat::reduce_product) by the rounding
+    /// errors of the reordered multiplications.
+    #[must_use = "method returns a new value and does not mutate the original value"]
+    fn reduce_product_fast(self) -> Self::Scalar;
+}
+
+macro_rules! impl_fast_std_float {
+    { $($scalar:ident, $bits:ident;)* } => {
+        $(
+        impl<const N: usize> FastStdFloat for Simd<$scalar, N>
+        where
+            LaneCount<N>: SupportedLaneCount,
+        {
+            type Scalar = $scalar;
+
+            #[inline]
+            fn mul_add_fast(self, a: Self, b: Self) -> Self {
+                if cfg!(any(target_feature = "fma", target_arch = "aarch64")) {
+                    self.mul_add(a, b)
+                } else {
+                    self * a + b
+                }
+            }
+
+            #[inline]
+            fn recip_fast(self) -> Self {
+                let one = Self::splat(1.0);
+                let mut y = self.estimate();
+                for _ in 0..<Self as RecipEstimate>::STEPS {
+                    y = y.mul_add_fast((-self).mul_add_fast(y, one), y);
+                }
+                y
+            }
+
+            #[inline]
+            fn exp_fast(self) -> Self {
+                use core::$scalar::consts::{LN_2, LOG2_E};
+
+                // As in `exp_approx`, but scaling by `2^k` in one step, which is only right while
+                // it is normal. The reduction cancels, so it is fused regardless.
+                let ln_2_lo = (core::f64::consts::LN_2 - LN_2 as f64) + approx::LN_2_LO;
+                let k = (self * Self::splat(LOG2_E)).round();
+                let r = (-k).mul_add(Self::splat(LN_2), self);
+                let r = (-k).mul_add(Self::splat(ln_2_lo as $scalar), r);
+
+                // The degree of `AccuracyMode::Fast`.
+                let degree = if $scalar::MANTISSA_DIGITS > 24 { 9 } else { 4 };
+                let mut p = Self::splat(0.0);
+                for &c in approx::EXP[degree - 1].iter().rev() {
+                    p = p.mul_add_fast(r, Self::splat(c as $scalar));
+                }
+
+                let bias = Self::splat(($scalar::MAX_EXP - 1) as $scalar);
+                let shift = Simd::splat($scalar::MANTISSA_DIGITS as $bits - 1);
+                p * Self::from_bits((k + bias).cast::<$bits>() << shift)
+            }
+
+            #[inline]
+            fn ln_fast(self) -> Self {
+                use core::$scalar::consts::{LN_2, SQRT_2};
+
+                // As in `ln_approx`, but reading the exponent off the bits as they are.
+                let shift = Simd::splat($scalar::MANTISSA_DIGITS as $bits - 1);
+                let bits = self.to_bits();
+                let one = Self::splat(1.0).to_bits();
+                let m = Self::from_bits((bits & (Simd::splat(1) << shift) - Simd::splat(1)) | one);
+                let bias = Self::splat(($scalar::MAX_EXP - 1) as $scalar);
+                let e = (bits >> shift).cast::<$scalar>() - bias;
+                let high = m.simd_ge(Self::splat(SQRT_2));
+                let m = high.select(m * Self::splat(0.5), m);
+                let e = high.select(e + Self::splat(1.0), e);
+
+                let s = (m - Self::splat(1.0)) / (m + Self::splat(1.0));
+                let z = s * s;
+                // The degree of `AccuracyMode::Fast`.
+                let degree = if $scalar::MANTISSA_DIGITS > 24 { 11 } else { 5 };
+                let mut p = Self::splat(0.0);
+                for &c in approx::LN[(degree - 2) / 2].iter().rev() {
+                    p = p.mul_add_fast(z, Self::splat(c as $scalar));
+                }
+                let ln_m = Self::splat(2.0) * s * p;
+
+                let ln_2_lo = (core::f64::consts::LN_2 - LN_2 as f64) + approx::LN_2_LO;
+                let ln_m = e.mul_add_fast(Self::splat(ln_2_lo as $scalar), ln_m);
+                e.mul_add_fast(Self::splat(LN_2), ln_m)
+            }
+
+            #[inline]
+            fn reduce_sum_fast(self) -> $scalar {
+                // SAFETY: `self` is a float vector and the result is its element type.
+                unsafe { intrinsics::simd_reduce_add_unordered(self) }
+            }
+
+            #[inline]
+            fn reduce_product_fast(self) -> $scalar {
+                // SAFETY: `self` is a float vector and the result is its element type.
+                unsafe { intrinsics::simd_reduce_mul_unordered(self) }
+            }
+        }
+        )*
+    }
+}
+
+impl_fast_std_float! {
+    f32, u32;
+    f64, u64;
+}
@@ -0,0 +1,262 @@
+//! Operations on 3D and 4D vectors.
+//!
+//! Most functions take a single vector held in a four-lane `f32` vector. A 3D vector uses the
+//! first three lanes, and the fourth lane is ignored. Coordinate conversions instead take one
+//! coordinate of many points per vector.
+
+use super::{
+    simd::{cmp::SimdPartialEq, simd_swizzle, LaneCount, Mask, Simd, SupportedLaneCount},
+    StdFloat,
+};
+
+/// Returns the dot product of the 4D vectors `a` and `b`.
+///
+/// The products of the first and third lanes and of the second and fourth lanes are added
+/// first, and then the two sums, with two shuffle-and-add steps rather than a sequential
+/// reduction. This is the order a dedicated dot product instruction such as `dpps` uses, so the
+/// result may differ from `reduce_sum` of the products in the last place.
+#[inline]
+#[must_use]
+pub fn dot4(a: Simd<f32, 4>, b: Simd<f32, 4>) -> f32 {
+    horizontal_sum(a * b)
+}
+
+/// Returns the dot product of the 3D vectors `a` and `b`, ignoring the fourth lane of both.
+///
+/// The products are summed in the same order as in [`dot4`]. Whatever the fourth lanes hold,
+/// even an infinity or a `NaN`, does not affect the result.
+#[inline]
+#[must_use]
+pub fn dot3(a: Simd<f32, 4>, b: Simd<f32, 4>) -> f32 {
+    horizontal_sum(xyz().select(a * b, Simd::splat(0.0)))
+}
+
+/// Returns the cross product of the 3D vectors `a` and `b`, with the fourth lane set to zero.
+///
+/// Each lane is computed as `a[j] * b[k] - a[k] * b[j]`. Both products are rounded before the
+/// subtraction: fusing one of them with a multiply-add would be slightly more accurate, but the
+/// unfused form keeps `cross3(b, a)` exactly equal to `-cross3(a, b)` and the cross product of a
+/// vector with itself exactly zero.
+#[inline]
+#[must_use]
+pub fn cross3(a: Simd<f32, 4>, b: Simd<f32, 4>) -> Simd<f32, 4> {
+    let a_yzx = simd_swizzle!(a, [1, 2, 0, 3]);
+    let b_yzx = simd_swizzle!(b, [1, 2, 0, 3]);
+    let a_zxy = simd_swizzle!(a, [2, 0, 1, 3]);
+    let b_zxy = simd_swizzle!(b, [2, 0, 1, 3]);
+    let cross = a_yzx * b_zxy - a_zxy * b_yzx;
+    xyz().select(cross, Simd::splat(0.0))
+}
+
+/// Scales the 3D vector `v` to unit length, with the fourth lane set to zero.
+///
+/// There is no portable reciprocal square root estimate to build on, so the reciprocal of the
+/// length is computed once, with a square root and a division, and then multiplied into each
+/// lane. Each lane of the result is within a few units in the last place of the exact one.
+///
+/// The zero vector is returned as the zero vector. The squared length is computed in `f32`, so
+/// a vector whose squared length underflows to zero also results in the zero vector, and one
+/// whose squared length overflows to infinity results in zeros or `NaN`s.
+#[inline]
+#[must_use]
+pub fn normalize3(v: Simd<f32, 4>) -> Simd<f32, 4> {
+    let len_sq = Simd::splat(dot3(v, v));
+    let scaled = v * (Simd::splat(1.0) / len_sq.sqrt());
+    (xyz() & len_sq.simd_ne(Simd::splat(0.0))).select(scaled, Simd::splat(0.0))
+}
+
+/// Scales the 3D vector `v` down so that its length does not exceed `max_len`, leaving the
+/// fourth lane unchanged.
+///
+/// A vector no longer than `max_len`, including the zero vector, is returned unchanged. A longer
+/// one keeps its direction and is scaled to a length within a few units in the last place of
+/// `max_len`. `max_len` is assumed to be non-negative.
+///
+/// As in [`normalize3`], the squared length is computed in `f32`, so a vector whose squared
+/// length overflows to infinity is scaled to zeros or `NaN`s.
+#[inline]
+#[must_use]
+pub fn clamp_length3(v: Simd<f32, 4>, max_len: f32) -> Simd<f32, 4> {
+    let len_sq = dot3(v, v);
+    if len_sq <= max_len * max_len {
+        return v;
+    }
+    xyz().select(v * Simd::splat(max_len / scalar_fn!(f32::sqrt)(len_sq)), v)
+}
+
+/// Reflects the 3D vector `incident` off a surface with the normal `normal`, with the fourth lane
+/// set to zero.
+///
+/// This is `incident - 2 * dot3(incident, normal) * normal`, with the subtraction fused into a
+/// multiply-add. `normal` is assumed to have unit length, as from [`normalize3`]; otherwise the
+/// result is not a reflection.
+#[inline]
+#[must_use]
+pub fn reflect3(incident: Simd<f32, 4>, normal: Simd<f32, 4>) -> Simd<f32, 4> {
+    let scale = Simd::splat(-2.0 * dot3(incident, normal));
+    xyz().select(normal.mul_add(scale, incident), Simd::splat(0.0))
+}
+
+/// Returns the projection of the 3D vector `a` onto the 3D vector `b`, with the fourth lane set
+/// to zero.
+///
+/// This is `dot3(a, b) / dot3(b, b) * b`. If `b` is the zero vector, the zero vector is
+/// returned.
+#[inline]
+#[must_use]
+pub fn project3(a: Simd<f32, 4>, b: Simd<f32, 4>) -> Simd<f32, 4> {
+    let len_sq = dot3(b, b);
+    if len_sq == 0.0 {
+        return Simd::splat(0.0);
+    }
+    xyz().select(b * Simd::splat(dot3(a, b) / len_sq), Simd::splat(0.0))
+}
+
+/// Returns the component of the 3D vector `a` orthogonal to the 3D vector `b`, with the fourth
+/// lane set to zero.
+///
+/// This is `a - project3(a, b)`, so the two add up to `a`. If `b` is the zero vector, `a` is
+/// returned.
+#[inline]
+#[must_use]
+pub fn reject3(a: Simd<f32, 4>, b: Simd<f32, 4>) -> Simd<f32, 4> {
+    xyz().select(a - project3(a, b), Simd::splat(0.0))
+}
+
+/// Returns the barycentric coordinates `(u, v, w)` of the 3D point `p` with respect to the
+/// triangle `(a, b, c)`, ignoring the fourth lanes.
+///
+/// The coordinates are the weights for which `u * a + v * b + w * c` is the projection of `p`
+/// onto the plane of the triangle, and they always add up to one. `p` is inside the triangle
+/// when all three are non-negative. They are computed from the dot products of the edges `b - a`
+/// and `c - a` with each other and with `p - a`, solving the 2×2 system by Cramer's rule, so
+/// each vertex gets exactly the weights `(1, 0, 0)`, `(0, 1, 0)` or `(0, 0, 1)`.
+///
+/// A degenerate triangle, whose vertices lie on one line or coincide, has no barycentric
+/// coordinates, and `(NaN, NaN, NaN)` is returned.
+#[inline]
+#[must_use]
+pub fn barycentric(
+    p: Simd<f32, 4>,
+    a: Simd<f32, 4>,
+    b: Simd<f32, 4>,
+    c: Simd<f32, 4>,
+) -> (f32, f32, f32) {
+    let (v0, v1, v2) = (b - a, c - a, p - a);
+    let d00 = dot3(v0, v0);
+    let d01 = dot3(v0, v1);
+    let d11 = dot3(v1, v1);
+    let d20 = dot3(v2, v0);
+    let d21 = dot3(v2, v1);
+    let denom = d00 * d11 - d01 * d01;
+    if denom == 0.0 {
+        return (f32::NAN, f32::NAN, f32::NAN);
+    }
+    let v = (d11 * d20 - d01 * d21) / denom;
+    let w = (d00 * d21 - d01 * d20) / denom;
+    (1.0 - v - w, v, w)
+}
+
+/// Multiplies the 4×4 matrix with the rows `rows` by the column vector `v`.
+///
+/// The matrix is stored row-major, so lane `i` of the result is [`dot4`]`(rows[i], v)`. With the
+/// usual homogeneous coordinates, a translation goes in the fourth lane of the first three rows,
+/// and applies to points, which have a fourth lane of `1.0`, but not to directions, which have a
+/// fourth lane of `0.0`. A matrix stored column-major has to be transposed first.
+#[inline]
+#[must_use]
+pub fn mat4_transform(rows: [Simd<f32, 4>; 4], v: Simd<f32, 4>) -> Simd<f32, 4> {
+    Simd::from_array(rows.map(|row| dot4(row, v)))
+}
+
+/// Returns the Hamilton product `a * b` of the quaternions `a` and `b`, each stored as
+/// `[x, y, z, w]` with the scalar part `w` in the fourth lane.
+///
+/// For unit quaternions representing rotations, the product represents the rotation `b`
+/// followed by the rotation `a`. Quaternion multiplication is not commutative.
+///
+/// Each lane is the sum of four products, accumulated with multiply-adds in a fixed order, so
+/// the result may differ from the exact product in the last few places.
+#[inline]
+#[must_use]
+pub fn quat_mul(a: Simd<f32, 4>, b: Simd<f32, 4>) -> Simd<f32, 4> {
+    // Negating lanes by multiplying with a sign is exact.
+    let sign = Simd::from_array([1.0, 1.0, 1.0, -1.0]);
+    let product = simd_swizzle!(a, [3, 3, 3, 3]) * b;
+    let product =
+        (simd_swizzle!(a, [0, 1, 2, 0]) * sign).mul_add(simd_swizzle!(b, [3, 3, 3, 0]), product);
+    let product =
+        (simd_swizzle!(a, [1, 2, 0, 1]) * sign).mul_add(simd_swizzle!(b, [2, 0, 1, 1]), product);
+    (-simd_swizzle!(a, [2, 0, 1, 2])).mul_add(simd_swizzle!(b, [1, 2, 0, 2]), product)
+}
+
+/// Interpolates along the shorter arc between the unit quaternions `a` and `b`, stored as in
+/// [`quat_mul`], weighting `b` by `t`.
+///
+/// The quaternions `b` and `-b` represent the same rotation, so if `a` and `b` are more than a
+/// quarter turn apart on the unit sphere, `-b` is used instead. The rotation then turns at a
+/// constant rate along the shortest path as `t` goes from zero to one.
+///
+/// Where `t` is `0.0`, `a` is returned exactly, and where `t` is `1.0`, `b` or `-b` is. When `a`
+/// and `b` are nearly parallel, the sine of the angle between them is too small to divide by
+/// accurately, and the normalized linear interpolation, which is indistinguishable there, is
+/// returned instead. Either way, the result has unit length to within a few units in the last
+/// place.
+#[inline]
+#[must_use]
+pub fn slerp(a: Simd<f32, 4>, b: Simd<f32, 4>, t: f32) -> Simd<f32, 4> {
+    let cos = dot4(a, b);
+    let (b, cos) = if cos < 0.0 { (-b, -cos) } else { (b, cos) };
+    if t == 0.0 {
+        return a;
+    }
+    if t == 1.0 {
+        return b;
+    }
+    if cos > 0.9995 {
+        let v = Simd::splat(t).mul_add(b - a, a);
+        return v * Simd::splat(1.0 / scalar_fn!(f32::sqrt)(dot4(v, v)));
+    }
+    let angle = scalar_fn!(f32::acos)(cos);
+    let (sin_t, cos_t) = scalar_fn!(f32::sin_cos | sincos)(t * angle);
+    let scale_b = sin_t / scalar_fn!(f32::sin)(angle);
+    let scale_a = cos_t - cos * scale_b;
+    a.mul_add(Simd::splat(scale_a), b * Simd::splat(scale_b))
+}
+
+/// Converts points from spherical coordinates to Cartesian coordinates, returning the `x`, `y`
+/// and `z` coordinates.
+///
+/// This uses the physics convention of ISO 80000-2: `r` is the distance from the origin,
+/// `theta` is the polar angle from the positive `z` axis, and `phi` is the azimuthal angle
+/// from the positive `x` axis towards the positive `y` axis, both in radians. The mathematics
+/// convention swaps the roles of `theta` and `phi`, so call this with them exchanged.
+#[inline]
+#[must_use]
+pub fn spherical_to_cartesian<const N: usize>(
+    r: Simd<f32, N>,
+    theta: Simd<f32, N>,
+    phi: Simd<f32, N>,
+) -> (Simd<f32, N>, Simd<f32, N>, Simd<f32, N>)
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    let (sin_theta, cos_theta) = theta.sin_cos();
+    let (sin_phi, cos_phi) = phi.sin_cos();
+    let r_xy = r * sin_theta;
+    (r_xy * cos_phi, r_xy * sin_phi, r * cos_theta)
+}
+
+/// Selects the first three lanes of a vector.
+#[inline]
+fn xyz() -> Mask<i32, 4> {
+    Mask::from_array([true, true, true, false])
+}
+
+/// Sums the lanes of `v` pairwise.
+#[inline]
+fn horizontal_sum(v: Simd<f32, 4>) -> f32 {
+    let v = v + simd_swizzle!(v, [2, 3, 0, 1]);
+    let v = v + simd_swizzle!(v, [1, 0, 3, 2]);
+    v[0]
+}
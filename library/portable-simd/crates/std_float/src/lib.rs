@@ -1,3 +1,4 @@
+#![cfg_attr(feature = "libm_fallback", no_std)]
 #![cfg_attr(
     feature = "as_crate",
     feature(core_intrinsics),
@@ -11,7 +12,11 @@
 
 use core::intrinsics::simd as intrinsics;
 
-use simd::{LaneCount, Simd, SupportedLaneCount};
+use simd::{
+    cmp::{SimdPartialEq, SimdPartialOrd},
+    num::{SimdFloat, SimdInt, SimdUint},
+    LaneCount, Simd, SupportedLaneCount,
+};
 
 #[cfg(feature = "as_crate")]
 mod experimental {
@@ -23,6 +28,67 @@ pub trait Sealed {}
 
 use crate::sealed::Sealed;
 
+/// Names the scalar function `$scalar::$fn` from `std`, or with the `libm_fallback` feature, its
+/// counterpart in `libm`, which is named `$libm` if given and `$fn` otherwise.
+#[cfg(not(feature = "libm_fallback"))]
+macro_rules! scalar_fn {
+    { $scalar:ident::$fn:ident $(| $libm:ident)? } => { $scalar::$fn };
+}
+
+#[cfg(feature = "libm_fallback")]
+macro_rules! scalar_fn {
+    { $scalar:ident::$fn:ident } => { libm::Libm::<$scalar>::$fn };
+    { $scalar:ident::$fn:ident | $libm:ident } => { libm::Libm::<$scalar>::$libm };
+}
+
+mod approx;
+mod color;
+mod complex;
+mod convert;
+mod det;
+mod dot;
+mod fast;
+mod gamma;
+mod geometry;
+mod recip;
+mod series;
+mod stats;
+
+pub use color::mix_srgb;
+pub use complex::{complex_abs, complex_mul, polar_to_complex};
+pub use convert::{
+    from_f16_bits, from_i32, from_i64, from_u32, from_u64, map_f16_bits, to_f16_bits,
+};
+pub use dot::sum_of_products;
+pub use fast::FastStdFloat;
+pub use geometry::{
+    barycentric, clamp_length3, cross3, dot3, dot4, mat4_transform, normalize3, project3, quat_mul,
+    reflect3, reject3, slerp, spherical_to_cartesian,
+};
+pub use series::cheby_eval;
+pub use stats::{count_finite, count_nan, mean, reduce_median, reduce_sorted, variance};
+
+/// How accurately the `*_with` methods of [`StdFloat`], such as
+/// [`exp_with`](StdFloat::exp_with), compute their results.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum AccuracyMode {
+    /// A short minimax polynomial, from the `*_approx` methods such as
+    /// [`exp_approx`](StdFloat::exp_approx).
+    ///
+    /// The error is within 64 units in the last place for `f32` and 1024 for `f64`, for
+    /// arguments of [`sin_with`](StdFloat::sin_with) and [`cos_with`](StdFloat::cos_with) up to
+    /// about `1e5` in magnitude.
+    Fast,
+    /// Exactly the same results as the method without a mode, such as [`exp`](StdFloat::exp).
+    #[default]
+    Default,
+    /// Within one unit in the last place of the exact result.
+    ///
+    /// `f32` elements are computed in `f64` and rounded once, and `f64` elements use the same
+    /// functions as [`Default`](Self::Default).
+    Precise,
+}
+
 /// This trait provides a possibly-temporary implementation of float functions
 /// that may, in the absence of hardware support, canonicalize to calling an
 /// operating system's `math.h` dynamically-loaded library (also known as a
@@ -42,7 +108,21 @@ pub trait Sealed {}
 /// when either the compiler or its supporting runtime functions are improved.
 /// For now this trait is available to permit experimentation with SIMD float
 /// operations that may lack hardware support, such as `mul_add`.
+///
+/// With the `libm_fallback` feature, the crate is `no_std`: the exponential,
+/// logarithmic and trigonometric functions, `mul_add`, `sqrt` and the rounding
+/// functions apply the pure-Rust scalar functions of the `libm` crate to each
+/// element, instead of intrinsics that may lower to calls into the operating
+/// system's `math.h`, and the other functions that call scalar functions from
+/// `std` call those of `libm` instead. `mul_add`, `sqrt` and the rounding
+/// functions are exact either way. The others are within a unit in the last
+/// place of the exact result, so the results may differ by up to two units in
+/// the last place.
 pub trait StdFloat: Sealed + Sized {
+    /// A vector of `i32` with the same number of elements, used for integer exponents and
+    /// category codes.
+    type Exponent;
+
     /// Elementwise fused multiply-add. Computes `(self * a) + b` with only one rounding error,
     /// yielding a more accurate result than an unfused multiply-add.
     ///
@@ -50,20 +130,85 @@ pub trait StdFloat: Sealed + Sized {
     /// architecture has a dedicated `fma` CPU instruction.  However, this is not always
     /// true, and will be heavily dependent on designing algorithms with specific target
     /// hardware in mind.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn mul_add(self, a: Self, b: Self) -> Self;
+
+    /// Elementwise fused multiply-subtract. Computes `(self * a) - b` with only one rounding
+    /// error.
+    ///
+    /// Negating `b` is exact, so this is [`mul_add`](Self::mul_add) with `-b`, and maps to the
+    /// `fmsub` variant of the `fma` instruction where there is one.
     #[inline]
     #[must_use = "method returns a new vector and does not mutate the original value"]
-    fn mul_add(self, a: Self, b: Self) -> Self {
-        unsafe { intrinsics::simd_fma(self, a, b) }
+    fn mul_sub(self, a: Self, b: Self) -> Self {
+        self.mul_add(a, unsafe { intrinsics::simd_neg(b) })
     }
 
-    /// Produces a vector where every element has the square root value
-    /// of the equivalently-indexed element in `self`
+    /// Elementwise negated fused multiply-add. Computes `-(self * a) + b` with only one
+    /// rounding error.
+    #[inline]
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn neg_mul_add(self, a: Self, b: Self) -> Self {
+        unsafe { intrinsics::simd_neg(self) }.mul_add(a, b)
+    }
+
+    /// Elementwise negated fused multiply-subtract. Computes `-(self * a) - b` with only one
+    /// rounding error.
     #[inline]
     #[must_use = "method returns a new vector and does not mutate the original value"]
-    fn sqrt(self) -> Self {
-        unsafe { intrinsics::simd_fsqrt(self) }
+    fn neg_mul_sub(self, a: Self, b: Self) -> Self {
+        unsafe { intrinsics::simd_neg(self).mul_add(a, intrinsics::simd_neg(b)) }
     }
 
+    /// Evaluates the polynomial with the coefficients `coeffs` at every element of `self`, by
+    /// Horner's method.
+    ///
+    /// The coefficients go from the highest degree to the constant term, so `[a, b, c]` is
+    /// `a * x * x + b * x + c`. Each step is a [`mul_add`](Self::mul_add), which maps to an `fma`
+    /// instruction and rounds once. No coefficients evaluate to zero.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn poly(self, coeffs: &[Self]) -> Self;
+
+    /// Produces a vector where every element has the square root value
+    /// of the equivalently-indexed element in `self`
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn sqrt(self) -> Self;
+
+    /// Approximates the reciprocal of each element, trading accuracy for speed compared to
+    /// [`recip`](SimdFloat::recip).
+    ///
+    /// This refines an estimate of the reciprocal with Newton-Raphson steps instead of dividing.
+    /// On x86 with SSE, `f32` starts from the hardware estimate of the `rcpps` instruction and
+    /// takes a single step; elsewhere, and for `f64`, the estimate comes from the bits of the
+    /// element and takes more steps, so this may be no faster than `recip`.
+    ///
+    /// Unlike `recip`, the result is not correctly rounded, but is within 3 units in the last
+    /// place of the exact result. Zeros and subnormal elements produce infinities, and elements
+    /// whose reciprocal is subnormal, including infinities, produce zeros, of the same sign as
+    /// the element.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn recip_approx(self) -> Self;
+
+    /// Produces a vector where every element has the length of the hypotenuse of a right-angle
+    /// triangle with legs of the equivalently-indexed elements in `self` and `other`, that is,
+    /// `sqrt(self * self + other * other)`.
+    ///
+    /// The smaller leg is divided by the larger before squaring, so the result does not overflow
+    /// or underflow unless the exact result does, and is within two units in the last place of
+    /// it. As with the scalar `hypot`, an infinite leg produces infinity even if the other is
+    /// `NaN`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn hypot(self, other: Self) -> Self;
+
+    /// Produces a vector where every element has the cube root of the value
+    /// in the equivalently-indexed element in `self`.
+    ///
+    /// Unlike raising to the power of `1.0 / 3.0`, this takes the real cube root of negative
+    /// elements, so `-8.0` produces `-2.0`. The result is within one unit in the last place of
+    /// the exact result.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn cbrt(self) -> Self;
+
     /// Produces a vector where every element has the sine of the value
     /// in the equivalently-indexed element in `self`.
     #[must_use = "method returns a new vector and does not mutate the original value"]
@@ -74,6 +219,116 @@ fn sqrt(self) -> Self {
     #[must_use = "method returns a new vector and does not mutate the original value"]
     fn cos(self) -> Self;
 
+    /// Produces a pair of vectors, where every element has the sine and the cosine of the value
+    /// in the equivalently-indexed element in `self`.
+    ///
+    /// This is cheaper than calling [`sin`](Self::sin) and [`cos`](Self::cos) separately, as
+    /// both share a single argument reduction. The results are within a unit in the last place
+    /// of `(self.sin(), self.cos())`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn sin_cos(self) -> (Self, Self);
+
+    /// Produces a vector where every element has the sine of the value
+    /// in the equivalently-indexed element in `self`, with the same bits on every target.
+    ///
+    /// Unlike [`sin`](Self::sin), which may call into the target's math library, this is
+    /// computed in Rust from correctly rounded operations only, with a Payne–Hanek argument
+    /// reduction for large elements, so it does not depend on the target or on any feature.
+    /// The result is within one unit in the last place of the exact result.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn sin_det(self) -> Self;
+
+    /// Produces a vector where every element has the cosine of the value
+    /// in the equivalently-indexed element in `self`, with the same bits on every target.
+    ///
+    /// This is the counterpart of [`sin_det`](Self::sin_det), with the same guarantees.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn cos_det(self) -> Self;
+
+    /// Produces a vector where every element has the arcsine of the value
+    /// in the equivalently-indexed element in `self`, in radians in the range `[-π / 2, π / 2]`.
+    ///
+    /// Elements outside of `[-1.0, 1.0]` produce `NaN`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn asin(self) -> Self;
+
+    /// Produces a vector where every element has the arccosine of the value
+    /// in the equivalently-indexed element in `self`, in radians in the range `[0, π]`.
+    ///
+    /// Elements outside of `[-1.0, 1.0]` produce `NaN`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn acos(self) -> Self;
+
+    /// Produces a vector where every element has the arctangent of the value
+    /// in the equivalently-indexed element in `self`, in radians in the range `[-π / 2, π / 2]`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn atan(self) -> Self;
+
+    /// Produces a vector where every element has the four-quadrant arctangent of the
+    /// equivalently-indexed elements in `self` (the `y` coordinate) and `x`, in radians in the
+    /// range `[-π, π]`.
+    ///
+    /// Signed zeros and infinities are handled as by the scalar `atan2`: for example,
+    /// `(0.0).atan2(-0.0)` is `π`, `(-0.0).atan2(-0.0)` is `-π`, and
+    /// `(INFINITY).atan2(NEG_INFINITY)` is `3π / 4`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn atan2(self, x: Self) -> Self;
+
+    /// Produces a vector where every element has the hyperbolic sine of the value
+    /// in the equivalently-indexed element in `self`.
+    ///
+    /// This is computed from [`exp`](Self::exp), without cancellation for small elements and
+    /// without intermediate overflow for large ones, and is within a few units in the last place
+    /// of the exact result. Elements too large for the result to be finite produce infinity of the
+    /// same sign.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn sinh(self) -> Self;
+
+    /// Produces a vector where every element has the hyperbolic cosine of the value
+    /// in the equivalently-indexed element in `self`.
+    ///
+    /// This is computed from [`exp`](Self::exp), without intermediate overflow for large
+    /// elements, and is within a few units in the last place of the exact result. Elements too
+    /// large in magnitude for the result to be finite produce infinity.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn cosh(self) -> Self;
+
+    /// Produces a vector where every element has the hyperbolic tangent of the value
+    /// in the equivalently-indexed element in `self`.
+    ///
+    /// This is computed from [`exp`](Self::exp), without cancellation for small elements, and is
+    /// within a few units in the last place of the exact result. Large elements, including
+    /// infinities, produce `1.0` or `-1.0` rather than `NaN`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn tanh(self) -> Self;
+
+    /// Produces a vector where every element has the inverse hyperbolic sine of the value
+    /// in the equivalently-indexed element in `self`.
+    ///
+    /// This is computed from [`ln`](Self::ln) and [`sqrt`](Self::sqrt), without cancellation for
+    /// small elements, and is within a few units in the last place of the exact result.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn asinh(self) -> Self;
+
+    /// Produces a vector where every element has the inverse hyperbolic cosine of the value
+    /// in the equivalently-indexed element in `self`.
+    ///
+    /// This is computed from [`ln`](Self::ln) and [`sqrt`](Self::sqrt), without cancellation
+    /// close to `1.0`, and is within a few units in the last place of the exact result. Elements
+    /// less than `1.0` produce `NaN`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn acosh(self) -> Self;
+
+    /// Produces a vector where every element has the inverse hyperbolic tangent of the value
+    /// in the equivalently-indexed element in `self`.
+    ///
+    /// This is computed from [`ln`](Self::ln), without cancellation for small elements, and is
+    /// within a few units in the last place of the exact result. Elements equal to `1.0` or
+    /// `-1.0` produce infinity of the same sign, and elements outside of `[-1.0, 1.0]` produce
+    /// `NaN`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn atanh(self) -> Self;
+
     /// Produces a vector where every element has the exponential (base e) of the value
     /// in the equivalently-indexed element in `self`.
     #[must_use = "method returns a new vector and does not mutate the original value"]
@@ -84,18 +339,44 @@ fn sqrt(self) -> Self {
     #[must_use = "method returns a new vector and does not mutate the original value"]
     fn exp2(self) -> Self;
 
+    /// Produces a vector where every element has `exp(x) - 1` for the value `x` in the
+    /// equivalently-indexed element in `self`.
+    ///
+    /// Unlike subtracting one from [`exp`](Self::exp), this is accurate for elements close to
+    /// zero: elements smaller in magnitude than `ln(2) / 2` use a Taylor polynomial, and larger
+    /// ones, where there is little cancellation, subtract directly. The result is within a few
+    /// units in the last place of the exact result.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn exp_m1(self) -> Self;
+
     /// Produces a vector where every element has the natural logarithm of the value
     /// in the equivalently-indexed element in `self`.
     #[must_use = "method returns a new vector and does not mutate the original value"]
     fn ln(self) -> Self;
 
+    /// Produces a vector where every element has `ln(1 + x)` for the value `x` in the
+    /// equivalently-indexed element in `self`.
+    ///
+    /// Unlike taking [`ln`](Self::ln) of one plus each element, this is accurate for elements
+    /// close to zero: elements smaller in magnitude than `1 / 16` use a Taylor polynomial. Larger
+    /// ones take the logarithm of the rounded `1 + x`, and then correct for the rounding by
+    /// dividing by `(1 + x) - 1` in place of `x`, as shown by Kahan. The result is within a few
+    /// units in the last place of the exact result.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn ln_1p(self) -> Self;
+
     /// Produces a vector where every element has the logarithm with respect to an arbitrary
     /// in the equivalently-indexed elements in `self` and `base`.
-    #[inline]
+    ///
+    /// This divides [`log2`](Self::log2) of `self` by that of `base`, rather than the natural
+    /// logarithms, because `log2` is exact for powers of two. So for bases that are powers of
+    /// two, the only error beyond that of `log2` is the rounding of the division, and for
+    /// example `log(8.0, 2.0)` is exactly `3.0`. Elements with a base of `10.0` use
+    /// [`log10`](Self::log10) directly, so that `log(1000.0, 10.0)` is exactly `3.0` rather than
+    /// the `2.9999999999999996` of the naive quotient. Otherwise, the result is within two units
+    /// in the last place of the exact result.
     #[must_use = "method returns a new vector and does not mutate the original value"]
-    fn log(self, base: Self) -> Self {
-        unsafe { intrinsics::simd_div(self.ln(), base.ln()) }
-    }
+    fn log(self, base: Self) -> Self;
 
     /// Produces a vector where every element has the base-2 logarithm of the value
     /// in the equivalently-indexed element in `self`.
@@ -107,59 +388,1153 @@ fn log(self, base: Self) -> Self {
     #[must_use = "method returns a new vector and does not mutate the original value"]
     fn log10(self) -> Self;
 
+    /// Approximates the exponential (base e) of each element with a minimax polynomial of the
+    /// given degree, trading accuracy for speed compared to [`exp`](Self::exp).
+    ///
+    /// `degree` is clamped to `1..=11`. The maximum error observed in testing, in units in the
+    /// last place of the result, is:
+    ///
+    /// | degree | `f32`  | `f64`  |
+    /// |--------|--------|--------|
+    /// | 1      | 3e5    | 2e14   |
+    /// | 2      | 2e4    | 8e12   |
+    /// | 3      | 700    | 4e11   |
+    /// | 4      | 30     | 2e10   |
+    /// | 5      | 2      | 4e8    |
+    /// | 6      | 1      | 9e6    |
+    /// | 7      | 1      | 2e5    |
+    /// | 8      | 1      | 4e3    |
+    /// | 9      | 1      | 70     |
+    /// | 10     | 1      | 3      |
+    /// | 11     | 1      | 1      |
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn exp_approx(self, degree: u32) -> Self;
+
+    /// Approximates the sine of each element with a minimax polynomial of the given degree,
+    /// trading accuracy for speed compared to [`sin`](Self::sin).
+    ///
+    /// `degree` is clamped to `3..=17`, and even degrees are rounded up since the polynomial is
+    /// odd. Arguments are reduced to `[-π / 2, π / 2]` by subtracting multiples of `π`, which
+    /// loses precision for arguments larger than about `1e5`. For smaller arguments, the maximum
+    /// error observed in testing, in units in the last place of the result, is:
+    ///
+    /// | degree | `f32`  | `f64`  |
+    /// |--------|--------|--------|
+    /// | 3      | 7e4    | 4e13   |
+    /// | 5      | 1e3    | 5e11   |
+    /// | 7      | 10     | 5e9    |
+    /// | 9      | 2      | 3e7    |
+    /// | 11     | 2      | 1e5    |
+    /// | 13     | 2      | 300    |
+    /// | 15     | 2      | 2      |
+    /// | 17     | 2      | 2      |
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn sin_approx(self, degree: u32) -> Self;
+
+    /// Approximates the natural logarithm of each element with a minimax polynomial of the given
+    /// degree, trading accuracy for speed compared to [`ln`](Self::ln).
+    ///
+    /// `degree` is clamped to `3..=15`, and even degrees are rounded up since the polynomial is
+    /// odd. The maximum error observed in testing, in units in the last place of the result, is:
+    ///
+    /// | degree | `f32`  | `f64`  |
+    /// |--------|--------|--------|
+    /// | 3      | 200    | 2e11   |
+    /// | 5      | 3      | 6e8    |
+    /// | 7      | 2      | 4e6    |
+    /// | 9      | 2      | 2e4    |
+    /// | 11     | 2      | 200    |
+    /// | 13     | 2      | 3      |
+    /// | 15     | 2      | 2      |
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn ln_approx(self, degree: u32) -> Self;
+
+    /// Approximates the hyperbolic tangent of each element with a rational function, trading
+    /// accuracy for speed compared to [`tanh`](Self::tanh).
+    ///
+    /// This is the `[7/6]` Padé approximant of `tanh`, which is much cheaper than `tanh` but has
+    /// an absolute error of up to `1e-4`, so it suits uses such as neural network activations
+    /// where throughput matters more than precision. Elements with a magnitude of at least `5.0`,
+    /// including infinities, produce exactly `1.0` or `-1.0`, and the result is odd in `self`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn tanh_approx(self) -> Self;
+
+    /// Computes [`exp`](Self::exp) to the given accuracy.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn exp_with(self, mode: AccuracyMode) -> Self;
+
+    /// Computes [`ln`](Self::ln) to the given accuracy.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn ln_with(self, mode: AccuracyMode) -> Self;
+
+    /// Computes [`sin`](Self::sin) to the given accuracy.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn sin_with(self, mode: AccuracyMode) -> Self;
+
+    /// Computes [`cos`](Self::cos) to the given accuracy.
+    ///
+    /// [`AccuracyMode::Fast`] reduces the argument to `x = (k + 1 / 2) * π + r` with
+    /// `|r| <= π / 2`, and approximates `cos(x) = ±sin(r)` with
+    /// [`sin_approx`](Self::sin_approx).
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn cos_with(self, mode: AccuracyMode) -> Self;
+
+    /// Raises each element of `self` to the power of the equivalently-indexed element in `n`.
+    ///
+    /// This is computed as `exp(n * ln(|self|))`, so the error grows with the magnitude of
+    /// `n * ln(|self|)`, to within about `2 + |n * ln(|self|)|` units in the last place. A
+    /// negative element raised to an integer power takes the sign of the result from the parity
+    /// of the exponent. The special cases are those of the scalar `powf`:
+    ///
+    /// * `x.powf(0.0)` and `1.0.powf(y)` are `1.0` for any `x` and `y`, even `NaN`, and
+    ///   `(-1.0).powf(±∞)` is `1.0`.
+    /// * A finite negative element raised to a finite non-integer power is `NaN`.
+    /// * Zeros and infinities give zeros or infinities, negative if the element is negative and
+    ///   the exponent is an odd integer.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn powf(self, n: Self) -> Self;
+
+    /// Raises each element of `self` to the power of the equivalently-indexed integer in `n`.
+    ///
+    /// This uses exponentiation by squaring, which is faster and more accurate than `powf` for
+    /// small exponents. A negative exponent gives the reciprocal of the positive power, and an
+    /// exponent of zero gives `1.0` for any element, even `NaN`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn powi(self, n: Self::Exponent) -> Self;
+
+    /// Multiplies each element of `self` by two to the power of the equivalently-indexed integer
+    /// in `exp`, also known as `scalbn`.
+    ///
+    /// This adds to the exponent bits directly, so it is exact unless the result overflows, in
+    /// which case it is infinite with the sign of the element, or is subnormal, in which case it
+    /// is rounded once to the nearest subnormal or zero. Unlike `self * exp2(exp)`, the scale
+    /// factor itself never overflows or underflows. Zeros, infinities and `NaN`s are unchanged.
+    #[doc(alias = "scalbn")]
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn ldexp(self, exp: Self::Exponent) -> Self;
+
+    /// Raises each element of `self` to the power of the equivalently-indexed element in `exp`,
+    /// taking the real root of negative elements where `exp` is the reciprocal of an odd integer.
+    ///
+    /// `(-8.0).powf(1.0 / 3.0)` is `NaN`, since `1.0 / 3.0` is not an integer, but this returns
+    /// the real cube root `-2.0`. An exponent is taken to be `1 / k` for an odd integer `k` when
+    /// it is exactly equal to `1.0 / k.round()`, where `k` is the reciprocal of the exponent, so
+    /// exponents written as `1.0 / 3.0`, `1.0 / 5.0` or `-1.0 / 3.0` are detected. For all other
+    /// exponents, and for elements that are not negative, this matches scalar `powf`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn powf_real(self, exp: Self) -> Self;
+
+    /// Evaluates a uniform Catmull-Rom spline through the control points `p0`, `p1`, `p2` and
+    /// `p3` at the parameter `t`, elementwise.
+    ///
+    /// The curve runs from `p1` at `t = 0.0` to `p2` at `t = 1.0`, with `p0` and `p3` shaping the
+    /// tangents at either end. The cubic is evaluated with [`mul_add`](Self::mul_add).
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn catmull_rom(p0: Self, p1: Self, p2: Self, p3: Self, t: Self) -> Self;
+
+    /// Interpolates bilinearly between the corner values `c00`, `c10`, `c01` and `c11` of a unit
+    /// square at the coordinates `tx` and `ty`, elementwise.
+    ///
+    /// `cXY` is the value at `(tx, ty) = (X, Y)`. The corners interpolate in `tx` first, then the
+    /// results in `ty`. Every interpolation is evaluated with [`mul_add`](Self::mul_add) as
+    /// `t * b + (1 - t) * a`, so the corner values are returned exactly.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn bilerp(c00: Self, c10: Self, c01: Self, c11: Self, tx: Self, ty: Self) -> Self;
+
+    /// Wraps each element, an angle in radians, into the range `(-π, π]` by adding or
+    /// subtracting a multiple of `2π`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn wrap_pi(self) -> Self;
+
+    /// Returns the shortest signed rotation from the angle `other` to the angle `self`, in
+    /// radians, elementwise.
+    ///
+    /// This is `self - other` wrapped into `(-π, π]` with [`wrap_pi`](Self::wrap_pi), so
+    /// the difference between `0.1` and `6.2` is about `0.18` rather than `-6.1`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn angle_diff(self, other: Self) -> Self;
+
+    /// Interpolates between the angles `self` and `other`, in radians, along the shorter arc
+    /// between them, elementwise.
+    ///
+    /// The result is wrapped into `(-π, π]` like [`angle_diff`](Self::angle_diff), so
+    /// interpolating from `0.1` to `6.2` passes through `0.0` rather than `π`. When `t` is `0.0`
+    /// or `1.0` and the angles are already within `(-π, π]`, `self` or `other` is returned
+    /// exactly.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn lerp_angle(self, other: Self, t: Self) -> Self;
+
+    /// Wraps each element into the range `[lo, hi)` by adding or subtracting a multiple of
+    /// `hi - lo`, elementwise.
+    ///
+    /// This is `lo + (self - lo).rem_euclid(hi - lo)`, with the result kept strictly below `hi`
+    /// where rounding would otherwise reach it. A `NaN` element results in `NaN`. Otherwise, if
+    /// `lo == hi` the result is `lo`, and an element that is infinite, or so far from `lo` that
+    /// `self - lo` overflows, results in `NaN`. The result is unspecified if `lo > hi`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn wrap_range(self, lo: Self, hi: Self) -> Self;
+
+    /// Adds `rhs` to each element and clamps the sum to the range `[min, max]`, elementwise.
+    ///
+    /// This is equivalent to `(self + rhs).clamp(min, max)` with [`clamp`](Self::clamp), so it
+    /// never panics: a `NaN` sum is returned as is, a `NaN` bound leaves that side of the range
+    /// unbounded, and a lane where `min > max` produces `max`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn add_clamped(self, rhs: Self, min: Self, max: Self) -> Self;
+
+    /// Produces a vector where every element has the exponential (base e) of the value in the
+    /// equivalently-indexed element in `self`, limited to at most the equivalently-indexed
+    /// element in `max`.
+    ///
+    /// Where [`exp`](Self::exp) would overflow to infinity, this returns `max` instead. A `NaN`
+    /// element results in `NaN`, as from `exp`, rather than `max`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn exp_clamped(self, max: Self) -> Self;
+
     /// Returns the smallest integer greater than or equal to each element.
     #[must_use = "method returns a new vector and does not mutate the original value"]
-    #[inline]
-    fn ceil(self) -> Self {
-        unsafe { intrinsics::simd_ceil(self) }
-    }
+    fn ceil(self) -> Self;
 
     /// Returns the largest integer value less than or equal to each element.
     #[must_use = "method returns a new vector and does not mutate the original value"]
-    #[inline]
-    fn floor(self) -> Self {
-        unsafe { intrinsics::simd_floor(self) }
-    }
+    fn floor(self) -> Self;
 
-    /// Rounds to the nearest integer value. Ties round toward zero.
+    /// Rounds to the nearest integer value. Ties round away from zero.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn round(self) -> Self;
+
+    /// Rounds to the nearest integer value. Ties round away from zero, so `2.5` rounds to `3.0`
+    /// and `-2.5` rounds to `-3.0`.
+    ///
+    /// This is the same as [`round`](Self::round), for code that wants to name its tie-breaking
+    /// policy explicitly.
     #[must_use = "method returns a new vector and does not mutate the original value"]
     #[inline]
-    fn round(self) -> Self {
-        unsafe { intrinsics::simd_round(self) }
+    fn round_half_away(self) -> Self {
+        self.round()
     }
 
+    /// Rounds to the nearest integer value. Ties round toward positive infinity, so `2.5`
+    /// rounds to `3.0` and `-2.5` rounds to `-2.0`.
+    ///
+    /// Results that round to zero keep the sign of the input, so `-0.5` rounds to `-0.0`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn round_half_up(self) -> Self;
+
+    /// Rounds to the nearest integer value. Ties round toward negative infinity, so `2.5`
+    /// rounds to `2.0` and `-2.5` rounds to `-3.0`.
+    ///
+    /// Results that round to zero keep the sign of the input, so `0.5` rounds to `0.0`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn round_half_down(self) -> Self;
+
     /// Returns the floating point's integer value, with its fractional part removed.
     #[must_use = "method returns a new vector and does not mutate the original value"]
-    #[inline]
-    fn trunc(self) -> Self {
-        unsafe { intrinsics::simd_trunc(self) }
-    }
+    fn trunc(self) -> Self;
 
     /// Returns the floating point's fractional value, with its integer part removed.
     #[must_use = "method returns a new vector and does not mutate the original value"]
     fn fract(self) -> Self;
+
+    /// Produces a vector where every element is the category of the equivalently-indexed
+    /// element in `self`, coded as the discriminant of the scalar [`FpCategory`], that is,
+    /// `FpCategory::Nan as i32` for `NaN`s, `FpCategory::Infinite as i32` for infinities, and so
+    /// on for zeros, subnormals and normal elements.
+    ///
+    /// [`FpCategory`]: core::num::FpCategory
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn classify(self) -> Self::Exponent;
+
+    /// Produces a vector where every element is `-1`, `0` or `1` as the equivalently-indexed
+    /// element in `self` is less than, equal to or greater than the one in `other`, under the
+    /// IEEE 754 `totalOrder` predicate.
+    ///
+    /// This is the vector analogue of the scalar `total_cmp`. It orders negative `NaN`s below
+    /// negative infinity, `-0.0` below `+0.0`, and positive `NaN`s above positive infinity, and
+    /// `NaN`s by their payloads, so that it is a total order suitable for sorting keys.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn total_cmp(self, other: Self) -> Self::Exponent;
+
+    /// Produces a vector where every element is the minimum of the equivalently-indexed
+    /// elements in `self` and `other`, as the IEEE 754-2019 `minimum` operation.
+    ///
+    /// Unlike `simd_min`, which ignores `NaN`s, a `NaN` in either vector produces `NaN`, and
+    /// `-0.0` is treated as less than `+0.0`. Each element matches the scalar `minimum`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn minimum(self, other: Self) -> Self;
+
+    /// Produces a vector where every element is the maximum of the equivalently-indexed
+    /// elements in `self` and `other`, as the IEEE 754-2019 `maximum` operation.
+    ///
+    /// Unlike `simd_max`, which ignores `NaN`s, a `NaN` in either vector produces `NaN`, and
+    /// `+0.0` is treated as greater than `-0.0`. Each element matches the scalar `maximum`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn maximum(self, other: Self) -> Self;
+
+    /// Produces a vector where every element is the midpoint of the equivalently-indexed
+    /// elements in `self` and `other`, that is, `(self + other) / 2`.
+    ///
+    /// Unlike that formula, this does not overflow when the sum does, so the midpoint of `MAX`
+    /// and `MAX` is `MAX`. Elements that are large enough to overflow are halved before they are
+    /// added, while small ones, which could underflow if halved, are added as they are. As with
+    /// the scalar `midpoint`, a `NaN`, or infinities of opposite signs, produce `NaN`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn midpoint(self, other: Self) -> Self;
+
+    /// Produces a vector where every element has the gamma function of the value in the
+    /// equivalently-indexed element in `self`.
+    ///
+    /// This uses the Lanczos approximation, evaluated in `f64` for both element types, and the
+    /// reflection formula `Γ(x) Γ(1 - x) = π / sin(πx)` for elements below one half. The relative
+    /// error is below `1e-14` for moderate elements, growing to about `1e-13` as the result
+    /// approaches overflow. Positive integers whose factorials are integers below `2^53` produce
+    /// them exactly, so `5.0` produces `24.0`. `±0.0` produces infinity of the same sign, negative
+    /// integers and negative infinity produce `NaN`, and positive infinity produces itself.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn gamma(self) -> Self;
+
+    /// Produces a vector where every element has the natural logarithm of the absolute value of
+    /// the gamma function of the value in the equivalently-indexed element in `self`.
+    ///
+    /// This uses the same approximation as [`gamma`](Self::gamma), but stays in the logarithm
+    /// throughout, so it does not overflow for large elements. `1.0` and `2.0` produce exactly
+    /// `0.0`, and zero, negative integers and both infinities produce positive infinity.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn lgamma(self) -> Self;
+
+    /// Produces a vector where every element is the equivalently-indexed element in `self`
+    /// clamped into the interval between the equivalently-indexed elements in `min` and `max`.
+    ///
+    /// Each lane should have `min <= max`. The result is computed as
+    /// `self.simd_max(min).simd_min(max)`, without branches, so a lane where `min > max`
+    /// deterministically produces `max`. A `NaN` in `self` is propagated, while a `NaN` bound
+    /// leaves that side of the interval unbounded.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn clamp(self, min: Self, max: Self) -> Self;
+
+    /// Produces a vector where every element is the quotient of Euclidean division of the
+    /// equivalently-indexed elements in `self` and `rhs`.
+    ///
+    /// This is `(self / rhs).trunc()`, adjusted by one towards negative infinity for positive
+    /// `rhs` or towards positive infinity for negative `rhs` wherever the remainder of the
+    /// truncating division is negative, so that `self == rhs * q + r` with
+    /// `r = self.rem_euclid(rhs)`, up to rounding. Each element matches the scalar
+    /// `div_euclid`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn div_euclid(self, rhs: Self) -> Self;
+
+    /// Produces a vector where every element is the least nonnegative remainder of the
+    /// equivalently-indexed element in `self` modulo the one in `rhs`.
+    ///
+    /// The remainder of the truncating division, `self % rhs`, is exact; where it is negative,
+    /// `rhs.abs()` is added to it. As with the scalar `rem_euclid`, which each element matches,
+    /// that addition can round up to `rhs.abs()` for tiny negative remainders.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn rem_euclid(self, rhs: Self) -> Self;
 }
 
 impl<const N: usize> Sealed for Simd<f32, N> where LaneCount<N>: SupportedLaneCount {}
 impl<const N: usize> Sealed for Simd<f64, N> where LaneCount<N>: SupportedLaneCount {}
 
+/// The methods that are implemented the same way for every element type.
+/// Implements methods that call `$intrinsic`, or with the `libm_fallback` feature, apply the
+/// `libm` function `$libm` to each element. Unlike those of `impl_float!`, these are exact, and
+/// their intrinsics need no workaround on any target.
+macro_rules! impl_libm_unary {
+    { $scalar:ident, $($fn:ident: $intrinsic:ident | $libm:ident,)* } => {
+        $(
+        #[inline]
+        fn $fn(self) -> Self {
+            #[cfg(feature = "libm_fallback")]
+            {
+                Self::from_array(self.to_array().map(libm::Libm::<$scalar>::$libm))
+            }
+
+            #[cfg(not(feature = "libm_fallback"))]
+            {
+                unsafe { intrinsics::$intrinsic(self) }
+            }
+        }
+        )*
+    }
+}
+
+macro_rules! impl_float_common {
+    { $scalar:ident, $bits:ident } => {
+        type Exponent = Simd<i32, N>;
+
+        #[inline]
+        fn mul_add(self, a: Self, b: Self) -> Self {
+            #[cfg(feature = "libm_fallback")]
+            {
+                let fma = libm::Libm::<$scalar>::fma;
+                Self::from_array(core::array::from_fn(|i| fma(self[i], a[i], b[i])))
+            }
+
+            #[cfg(not(feature = "libm_fallback"))]
+            {
+                unsafe { intrinsics::simd_fma(self, a, b) }
+            }
+        }
+
+        impl_libm_unary! {
+            $scalar,
+            sqrt: simd_fsqrt | sqrt,
+            ceil: simd_ceil | ceil,
+            floor: simd_floor | floor,
+            round: simd_round | round,
+            trunc: simd_trunc | trunc,
+        }
+
+        #[inline]
+        fn fract(self) -> Self {
+            self - self.trunc()
+        }
+
+        #[inline]
+        fn classify(self) -> Self::Exponent {
+            use core::num::FpCategory;
+
+            // With the sign bit cleared, the bits order the categories: zero, then subnormals
+            // below the smallest normal, then normals below infinity, then `NaN`s.
+            let magnitude = self.to_bits() & !Self::splat(-0.0).to_bits();
+            let bits = |x: $scalar| Simd::splat(x.to_bits());
+            let code = |category: FpCategory| Simd::splat(category as i32);
+            let category = code(FpCategory::Normal);
+            let subnormal = magnitude.simd_lt(bits($scalar::MIN_POSITIVE)).cast::<i32>();
+            let category = subnormal.select(code(FpCategory::Subnormal), category);
+            let zero = magnitude.simd_eq(Simd::splat(0)).cast::<i32>();
+            let category = zero.select(code(FpCategory::Zero), category);
+            let infinite = magnitude.simd_eq(bits($scalar::INFINITY)).cast::<i32>();
+            let category = infinite.select(code(FpCategory::Infinite), category);
+            let nan = magnitude.simd_gt(bits($scalar::INFINITY)).cast::<i32>();
+            nan.select(code(FpCategory::Nan), category)
+        }
+
+        #[inline]
+        fn total_cmp(self, other: Self) -> Self::Exponent {
+            // Flipping every bit of negative elements and only the sign bit of positive ones
+            // maps the total order onto the order of the bits as unsigned integers.
+            let sign_bit = Self::splat(-0.0).to_bits();
+            let key = |x: Self| {
+                let bits = x.to_bits();
+                (bits & sign_bit).simd_eq(Simd::splat(0)).select(bits | sign_bit, !bits)
+            };
+            let (a, b) = (key(self), key(other));
+            let less = a.simd_lt(b).cast::<i32>();
+            let greater = a.simd_gt(b).cast::<i32>();
+            less.select(Simd::splat(-1), greater.select(Simd::splat(1), Simd::splat(0)))
+        }
+
+        #[inline]
+        fn minimum(self, other: Self) -> Self {
+            // Equal elements only differ if they are zeros of opposite signs, in which case the
+            // union of their bits is `-0.0`. Adding propagates either `NaN`.
+            let equal = Self::from_bits(self.to_bits() | other.to_bits());
+            let min = self.simd_lt(other).select(self, other.simd_lt(self).select(other, equal));
+            (self.is_nan() | other.is_nan()).select(self + other, min)
+        }
+
+        #[inline]
+        fn maximum(self, other: Self) -> Self {
+            let equal = Self::from_bits(self.to_bits() & other.to_bits());
+            let max = self.simd_gt(other).select(self, other.simd_gt(self).select(other, equal));
+            (self.is_nan() | other.is_nan()).select(self + other, max)
+        }
+
+        #[inline]
+        fn midpoint(self, other: Self) -> Self {
+            let lo = Self::splat($scalar::MIN_POSITIVE * 2.0);
+            let hi = Self::splat($scalar::MAX / 2.0);
+            let half = Self::splat(0.5);
+            let (abs_a, abs_b) = (SimdFloat::abs(self), SimdFloat::abs(other));
+            // Either the sum can't overflow, or one of the elements is too small to halve, or
+            // both can be halved.
+            let both_halved = self * half + other * half;
+            let mid = abs_b.simd_lt(lo).select(self * half + other, both_halved);
+            let mid = abs_a.simd_lt(lo).select(self + other * half, mid);
+            (abs_a.simd_le(hi) & abs_b.simd_le(hi)).select((self + other) * half, mid)
+        }
+
+        #[inline]
+        fn log(self, base: Self) -> Self {
+            let log = self.log2() / base.log2();
+            let ten = base.simd_eq(Self::splat(10.0));
+            if ten.any() {
+                ten.select(self.log10(), log)
+            } else {
+                log
+            }
+        }
+
+        #[inline]
+        fn gamma(self) -> Self {
+            gamma::gamma(self.cast::<f64>()).cast()
+        }
+
+        #[inline]
+        fn lgamma(self) -> Self {
+            gamma::lgamma(self.cast::<f64>()).cast()
+        }
+
+        #[inline]
+        fn poly(self, coeffs: &[Self]) -> Self {
+            // Starting from the leading coefficient rather than from zero keeps an infinite
+            // `self` from multiplying zero into `NaN`.
+            let Some((&leading, rest)) = coeffs.split_first() else {
+                return Self::splat(0.0);
+            };
+            rest.iter().fold(leading, |p, &c| p.mul_add(self, c))
+        }
+
+        #[inline]
+        fn clamp(self, min: Self, max: Self) -> Self {
+            // `simd_max` and `simd_min` drop `NaN`s, which ignores `NaN` bounds but would also
+            // replace a `NaN` in `self` with a bound.
+            self.is_nan().select(self, self.simd_max(min).simd_min(max))
+        }
+
+        #[inline]
+        fn div_euclid(self, rhs: Self) -> Self {
+            let q = (self / rhs).trunc();
+            let one = Self::splat(1.0);
+            let adjusted = rhs.simd_gt(Self::splat(0.0)).select(q - one, q + one);
+            (self % rhs).simd_lt(Self::splat(0.0)).select(adjusted, q)
+        }
+
+        #[inline]
+        fn rem_euclid(self, rhs: Self) -> Self {
+            let r = self % rhs;
+            r.simd_lt(Self::splat(0.0)).select(r + SimdFloat::abs(rhs), r)
+        }
+
+        #[inline]
+        fn hypot(self, other: Self) -> Self {
+            let (x, y) = (SimdFloat::abs(self), SimdFloat::abs(other));
+            let big = x.simd_max(y);
+            let ratio = x.simd_min(y) / big;
+            let hypot = big * ratio.mul_add(ratio, Self::splat(1.0)).sqrt();
+            // Both legs zero makes the ratio `NaN`, and `simd_max` and `simd_min` drop `NaN`s.
+            let hypot = big.simd_eq(Self::splat(0.0)).select(big, hypot);
+            let hypot = (x.is_nan() | y.is_nan()).select(Self::splat($scalar::NAN), hypot);
+            (x.is_infinite() | y.is_infinite()).select(Self::splat($scalar::INFINITY), hypot)
+        }
+
+        #[inline]
+        fn cbrt(self) -> Self {
+            let a = SimdFloat::abs(self);
+            // `2^e` for an exponent `e` within the normal range.
+            let pow2 = |e: i32| {
+                let bias = $scalar::MAX_EXP - 1;
+                $scalar::from_bits(((e + bias) as $bits) << ($scalar::MANTISSA_DIGITS - 1))
+            };
+
+            // Scale subnormals up by a power of two that is a multiple of three, so that their
+            // exponent can be divided, and the root scaled back down exactly.
+            let k = ($scalar::MANTISSA_DIGITS as i32 + 2) / 3 * 3;
+            let subnormal = a.simd_lt(Self::splat($scalar::MIN_POSITIVE));
+            let x = subnormal.select(a * Self::splat(pow2(k)), a);
+
+            // Dividing the bits by three roughly divides the exponent by three, giving an
+            // estimate within a few percent. The offset makes the estimate exact for `1.0`.
+            let one = Self::splat(1.0).to_bits();
+            let three = Simd::splat(3);
+            let mut y = Self::from_bits(x.to_bits() / three + (one - one / three));
+            // Each Newton step roughly squares the relative error.
+            let steps = if $scalar::MANTISSA_DIGITS > 24 { 4 } else { 3 };
+            for _ in 0..steps {
+                y += (x / (y * y) - y) * Self::splat(1.0 / 3.0);
+            }
+
+            let y = subnormal.select(y * Self::splat(pow2(-k / 3)), y);
+            let y = (a.simd_eq(Self::splat(0.0)) | !a.is_finite()).select(a, y);
+            y.copysign(self)
+        }
+
+        #[inline]
+        fn sin_cos(self) -> (Self, Self) {
+            use core::$scalar::consts::FRAC_2_PI;
+
+            // Reduce to `x = k * π / 2 + r` with `|r| <= π / 4`, with `π / 2` split into three
+            // parts. Each fused step is exact while `|k|` fits in half the mantissa; beyond that
+            // the reduction loses precision, so those elements fall back to `sin` and `cos`.
+            let half_pi = core::f64::consts::FRAC_PI_2;
+            let p1 = half_pi as $scalar;
+            let p2 = ((half_pi - p1 as f64) + approx::PI_LO / 2.0) as $scalar;
+            let p3 = ((half_pi - p1 as f64 - p2 as f64) + approx::PI_LO / 2.0
+                + approx::PI_LO_2 / 2.0) as $scalar;
+            let k = (self * Self::splat(FRAC_2_PI)).round();
+            let r = (-k).mul_add(Self::splat(p1), self);
+            let r = (-k).mul_add(Self::splat(p2), r);
+            let r = (-k).mul_add(Self::splat(p3), r);
+
+            // The kernels of fdlibm. `f32` only needs the first four terms of each.
+            let terms = if $scalar::MANTISSA_DIGITS > 24 { 6 } else { 4 };
+            let z = r * r;
+            let (mut ps, mut pc) = (Self::splat(0.0), Self::splat(0.0));
+            for i in (0..terms).rev() {
+                ps = ps.mul_add(z, Self::splat(approx::KERNEL_SIN[i] as $scalar));
+                pc = pc.mul_add(z, Self::splat(approx::KERNEL_COS[i] as $scalar));
+            }
+            let s = (r * z).mul_add(ps, r);
+            // `1 - z / 2` is rounded separately, and its rounding error added back.
+            let half_z = z * Self::splat(0.5);
+            let w = Self::splat(1.0) - half_z;
+            let c = w + (((Self::splat(1.0) - w) - half_z) + (z * z) * pc);
+
+            // Rotate by the quadrant `k mod 4`.
+            let q = k.cast::<i32>();
+            let bit = |q: Simd<i32, N>, b: i32| (q & Simd::splat(b)).simd_ne(Simd::splat(0));
+            let (sin, cos) = (bit(q, 1).select(c, s), bit(q, 1).select(s, c));
+            let sin = bit(q, 2).select(-sin, sin);
+            let cos = bit(q + Simd::splat(1), 2).select(-cos, cos);
+            // The reduction turns `-0.0` into `0.0`.
+            let sin = self.simd_eq(Self::splat(0.0)).select(self, sin);
+
+            let max_k = (1 << ($scalar::MANTISSA_DIGITS / 2)) as $scalar;
+            let large = SimdFloat::abs(k).simd_gt(Self::splat(max_k)) | self.is_infinite();
+            if large.any() {
+                (large.select(self.sin(), sin), large.select(self.cos(), cos))
+            } else {
+                (sin, cos)
+            }
+        }
+
+        #[inline]
+        fn sin_det(self) -> Self {
+            det::sin_cos(self.cast::<f64>()).0.cast()
+        }
+
+        #[inline]
+        fn cos_det(self) -> Self {
+            det::sin_cos(self.cast::<f64>()).1.cast()
+        }
+
+        #[inline]
+        fn exp_m1(self) -> Self {
+            use core::$scalar::consts::LN_2;
+
+            // The Taylor polynomial `x + x² / 2! + ... + x^n / n!`, with enough terms that the
+            // first one left out is below half a unit in the last place for `|x| <= ln(2) / 2`.
+            let n = if $scalar::MANTISSA_DIGITS > 24 { 14 } else { 8 };
+            let mut q = Self::splat(0.0);
+            let mut factorial = (2..=n).map(|k| k as $scalar).product::<$scalar>();
+            for k in (2..=n).rev() {
+                q = q.mul_add(self, Self::splat(1.0 / factorial));
+                factorial /= k as $scalar;
+            }
+            let small = (self * self).mul_add(q, self);
+            let large = self.exp() - Self::splat(1.0);
+            let em1 = SimdFloat::abs(self).simd_lt(Self::splat(LN_2 / 2.0)).select(small, large);
+            // Keeps the sign of zero.
+            self.simd_eq(Self::splat(0.0)).select(self, em1)
+        }
+
+        #[inline]
+        fn ln_1p(self) -> Self {
+            // The Taylor polynomial `x - x² / 2 + x³ / 3 - ... ± x^n / n`, with enough terms as
+            // in `exp_m1`, for `|x| < 1 / 16`.
+            let n = if $scalar::MANTISSA_DIGITS > 24 { 14 } else { 7 };
+            let mut q = Self::splat(0.0);
+            for k in (2..=n).rev() {
+                let sign = if k % 2 == 0 { -1.0 } else { 1.0 };
+                q = q.mul_add(self, Self::splat(sign / k as $scalar));
+            }
+            let small = (self * self).mul_add(q, self);
+
+            // Dividing by the rounded `(1 + x) - 1` in place of `x` cancels out most of the
+            // rounding error of `1 + x`.
+            let u = Self::splat(1.0) + self;
+            let large = u.ln() * (self / (u - Self::splat(1.0)));
+            let large = (u.is_infinite() & u.is_sign_positive()).select(u, large);
+            let ln = SimdFloat::abs(self).simd_lt(Self::splat(0.0625)).select(small, large);
+            self.simd_eq(Self::splat(0.0)).select(self, ln)
+        }
+
+        #[inline]
+        fn sinh(self) -> Self {
+            let a = SimdFloat::abs(self);
+            let em1 = a.exp_m1();
+            let small = Self::splat(0.5) * (em1 + em1 / (em1 + Self::splat(1.0)));
+            // `exp(a) / 2`, as the product of two halves so that it doesn't overflow before the
+            // result does.
+            let h = (a * Self::splat(0.5)).exp();
+            let half_exp = h * (h * Self::splat(0.5));
+            let large = half_exp - Self::splat(0.25) / half_exp;
+            let sinh = a.simd_le(Self::splat(2.0)).select(small, large);
+            sinh.copysign(self)
+        }
+
+        #[inline]
+        fn cosh(self) -> Self {
+            let h = (SimdFloat::abs(self) * Self::splat(0.5)).exp();
+            let half_exp = h * (h * Self::splat(0.5));
+            half_exp + Self::splat(0.25) / half_exp
+        }
+
+        #[inline]
+        fn tanh(self) -> Self {
+            let a2 = SimdFloat::abs(self) * Self::splat(2.0);
+            let em1 = a2.exp_m1();
+            let small = em1 / (em1 + Self::splat(2.0));
+            // An infinite `exp` makes this `1.0`.
+            let large = Self::splat(1.0) - Self::splat(2.0) / (a2.exp() + Self::splat(1.0));
+            let tanh = a2.simd_le(Self::splat(1.0)).select(small, large);
+            tanh.copysign(self)
+        }
+
+        // There are no intrinsics for the inverse trigonometric functions, so these apply the
+        // scalar functions to each element.
+        #[inline]
+        fn asin(self) -> Self {
+            Self::from_array(self.to_array().map(scalar_fn!($scalar::asin)))
+        }
+
+        #[inline]
+        fn acos(self) -> Self {
+            Self::from_array(self.to_array().map(scalar_fn!($scalar::acos)))
+        }
+
+        #[inline]
+        fn atan(self) -> Self {
+            Self::from_array(self.to_array().map(scalar_fn!($scalar::atan)))
+        }
+
+        #[inline]
+        fn atan2(self, x: Self) -> Self {
+            Self::from_array(core::array::from_fn(|i| scalar_fn!($scalar::atan2)(self[i], x[i])))
+        }
+
+        #[inline]
+        fn asinh(self) -> Self {
+            use core::$scalar::consts::LN_2;
+
+            let one = Self::splat(1.0);
+            let a = SimdFloat::abs(self);
+            // `ln(a + sqrt(a² + 1))`, rearranged as `ln_1p(a + a² / (1 + sqrt(a² + 1)))` so that
+            // it is accurate for small `a`.
+            let a2 = a * a;
+            let small = (a + a2 / (one + (a2 + one).sqrt())).ln_1p();
+            // Beyond this, `a² + 1` rounds to `a²`, and squaring `a` may overflow.
+            let large = a.ln() + Self::splat(LN_2);
+            let asinh = a.simd_gt(Self::splat(1.0 / $scalar::EPSILON)).select(large, small);
+            asinh.copysign(self)
+        }
+
+        #[inline]
+        fn acosh(self) -> Self {
+            use core::$scalar::consts::LN_2;
+
+            let one = Self::splat(1.0);
+            // `ln(x + sqrt(x² - 1))`, rearranged as `ln_1p(t + sqrt(t * (t + 2)))` with
+            // `t = x - 1` near one, and as `ln(2x - 1 / (x + sqrt(x² - 1)))` further out.
+            let t = self - one;
+            let near = (t + (t * (t + Self::splat(2.0))).sqrt()).ln_1p();
+            let far = (Self::splat(2.0) * self - one / (self + (self * self - one).sqrt())).ln();
+            let large = self.ln() + Self::splat(LN_2);
+            let acosh = self.simd_lt(Self::splat(2.0)).select(near, far);
+            let acosh = self.simd_gt(Self::splat(1.0 / $scalar::EPSILON)).select(large, acosh);
+            self.simd_lt(one).select(Self::splat($scalar::NAN), acosh)
+        }
+
+        #[inline]
+        fn atanh(self) -> Self {
+            // `ln((1 + a) / (1 - a)) / 2`, rearranged as `ln_1p(2a / (1 - a)) / 2` so that it is
+            // accurate for small `a`. Outside of `[-1, 1]`, the argument of `ln_1p` is less
+            // than `-1`, so the result is `NaN`.
+            let a = SimdFloat::abs(self);
+            let t = Self::splat(2.0) * a / (Self::splat(1.0) - a);
+            let atanh = Self::splat(0.5) * t.ln_1p();
+            atanh.copysign(self)
+        }
+
+        #[inline]
+        fn exp_approx(self, degree: u32) -> Self {
+            use core::$scalar::consts::{LN_2, LOG2_E};
+
+            // Beyond this, the result is zero or infinite, and clamping keeps `2^k` below from
+            // overflowing the exponent. Comparisons leave `NaN` unclamped.
+            let limit = ($scalar::MAX_EXP + $scalar::MANTISSA_DIGITS as i32) as $scalar * LN_2;
+            let limit = Self::splat(limit);
+            let x = self.simd_gt(limit).select(limit, self);
+            let x = x.simd_lt(-limit).select(-limit, x);
+
+            // Reduce to `x = k * ln(2) + r` with `|r| <= ln(2) / 2`, so `exp(x) = 2^k * exp(r)`.
+            let ln_2_lo = ((core::f64::consts::LN_2 - LN_2 as f64) + approx::LN_2_LO) as $scalar;
+            let k = (x * Self::splat(LOG2_E)).round();
+            let r = (-k).mul_add(Self::splat(LN_2), x);
+            let r = (-k).mul_add(Self::splat(ln_2_lo), r);
+
+            let mut p = Self::splat(0.0);
+            for &c in approx::EXP[degree.clamp(1, 11) as usize - 1].iter().rev() {
+                p = p.mul_add(r, Self::splat(c as $scalar));
+            }
+
+            // `2^k` may not be representable when the result is subnormal, so scale in two steps.
+            let pow2 = |k: Self| {
+                let bias = Self::splat(($scalar::MAX_EXP - 1) as $scalar);
+                let shift = Simd::splat($scalar::MANTISSA_DIGITS as $bits - 1);
+                Self::from_bits((k + bias).cast::<$bits>() << shift)
+            };
+            let k_hi = (k * Self::splat(0.5)).floor();
+            p * pow2(k_hi) * pow2(k - k_hi)
+        }
+
+        #[inline]
+        fn sin_approx(self, degree: u32) -> Self {
+            use core::$scalar::consts::{FRAC_1_PI, PI};
+
+            // Reduce to `x = k * π + r` with `|r| <= π / 2`, so `sin(x) = ±sin(r)`. `π` is
+            // split into three parts so that the reduction is accurate even for `f32`.
+            let pi_mid = (core::f64::consts::PI - PI as f64) as $scalar;
+            let pi_lo =
+                ((core::f64::consts::PI - PI as f64 - pi_mid as f64) + approx::PI_LO) as $scalar;
+            let k = (self * Self::splat(FRAC_1_PI)).round();
+            let r = (-k).mul_add(Self::splat(PI), self);
+            let r = (-k).mul_add(Self::splat(pi_mid), r);
+            let r = (-k).mul_add(Self::splat(pi_lo), r);
+
+            let z = r * r;
+            let mut p = Self::splat(0.0);
+            for &c in approx::SIN[(degree.clamp(3, 17) as usize - 2) / 2].iter().rev() {
+                p = p.mul_add(z, Self::splat(c as $scalar));
+            }
+            let sin_r = r * p;
+
+            let odd = SimdFloat::abs((k * Self::splat(0.5)).fract()).simd_eq(Self::splat(0.5));
+            odd.select(-sin_r, sin_r)
+        }
+
+        #[inline]
+        fn ln_approx(self, degree: u32) -> Self {
+            use core::$scalar::consts::{LN_2, SQRT_2};
+
+            // Scale subnormals up so that their exponent can be read from the bits.
+            let subnormal = self.simd_lt(Self::splat($scalar::MIN_POSITIVE));
+            let scale = ((1 as $bits) << ($scalar::MANTISSA_DIGITS - 1)) as $scalar;
+            let x = subnormal.select(self * Self::splat(scale), self);
+
+            // Split into `x = 2^e * m` with `m` in `[√2 / 2, √2)`.
+            let shift = Simd::splat($scalar::MANTISSA_DIGITS as $bits - 1);
+            let bits = x.to_bits();
+            let one = Self::splat(1.0).to_bits();
+            let m = Self::from_bits((bits & (Simd::splat(1) << shift) - Simd::splat(1)) | one);
+            let bias = Self::splat(($scalar::MAX_EXP - 1) as $scalar);
+            let e = (bits >> shift).cast::<$scalar>() - bias;
+            let e = subnormal.select(e - Self::splat(($scalar::MANTISSA_DIGITS - 1) as $scalar), e);
+            let high = m.simd_ge(Self::splat(SQRT_2));
+            let m = high.select(m * Self::splat(0.5), m);
+            let e = high.select(e + Self::splat(1.0), e);
+
+            // `ln(m) = 2 * atanh(s)` with `s = (m - 1) / (m + 1)`.
+            let s = (m - Self::splat(1.0)) / (m + Self::splat(1.0));
+            let z = s * s;
+            let mut p = Self::splat(0.0);
+            for &c in approx::LN[(degree.clamp(3, 15) as usize - 2) / 2].iter().rev() {
+                p = p.mul_add(z, Self::splat(c as $scalar));
+            }
+            let ln_m = Self::splat(2.0) * s * p;
+
+            let ln_2_lo = ((core::f64::consts::LN_2 - LN_2 as f64) + approx::LN_2_LO) as $scalar;
+            let ln = e.mul_add(Self::splat(LN_2), e.mul_add(Self::splat(ln_2_lo), ln_m));
+
+            let ln = self.simd_eq(Self::splat(0.0)).select(Self::splat($scalar::NEG_INFINITY), ln);
+            let ln = self.simd_eq(Self::splat($scalar::INFINITY)).select(self, ln);
+            let ln = self.simd_lt(Self::splat(0.0)).select(Self::splat($scalar::NAN), ln);
+            self.is_nan().select(self, ln)
+        }
+
+        #[inline]
+        fn recip_approx(self) -> Self {
+            use recip::RecipEstimate;
+
+            let one = Self::splat(1.0);
+            let mut y = self.estimate();
+            for _ in 0..<Self as RecipEstimate>::STEPS {
+                y = y.mul_add((-self).mul_add(y, one), y);
+            }
+
+            let tiny = SimdFloat::abs(self).simd_lt(Self::splat($scalar::MIN_POSITIVE));
+            let huge = SimdFloat::abs(self).simd_gt(Self::splat(1.0 / $scalar::MIN_POSITIVE));
+            let y = tiny.select(Self::splat($scalar::INFINITY).copysign(self), y);
+            let y = huge.select(Self::splat(0.0).copysign(self), y);
+            self.is_nan().select(self, y)
+        }
+
+        #[inline]
+        fn exp_with(self, mode: AccuracyMode) -> Self {
+            // The lowest degrees that stay within the error documented for `Fast`.
+            let degree = if $scalar::MANTISSA_DIGITS > 24 { 9 } else { 4 };
+            match mode {
+                AccuracyMode::Fast => self.exp_approx(degree),
+                AccuracyMode::Default => self.exp(),
+                AccuracyMode::Precise => self.cast::<f64>().exp().cast(),
+            }
+        }
+
+        #[inline]
+        fn ln_with(self, mode: AccuracyMode) -> Self {
+            let degree = if $scalar::MANTISSA_DIGITS > 24 { 11 } else { 5 };
+            match mode {
+                AccuracyMode::Fast => self.ln_approx(degree),
+                AccuracyMode::Default => self.ln(),
+                AccuracyMode::Precise => self.cast::<f64>().ln().cast(),
+            }
+        }
+
+        #[inline]
+        fn sin_with(self, mode: AccuracyMode) -> Self {
+            let degree = if $scalar::MANTISSA_DIGITS > 24 { 13 } else { 7 };
+            match mode {
+                AccuracyMode::Fast => self.sin_approx(degree),
+                AccuracyMode::Default => self.sin(),
+                AccuracyMode::Precise => self.cast::<f64>().sin().cast(),
+            }
+        }
+
+        #[inline]
+        fn cos_with(self, mode: AccuracyMode) -> Self {
+            use core::$scalar::consts::{FRAC_1_PI, PI};
+
+            match mode {
+                AccuracyMode::Fast => {
+                    // Adding `π / 2` and calling `sin_approx` would round the sum, losing the
+                    // relative accuracy near the zeros of `cos`, so reduce with the same three
+                    // parts of `π` as `sin_approx`, whose own reduction then leaves `r` as is.
+                    let pi_mid = (core::f64::consts::PI - PI as f64) as $scalar;
+                    let pi_lo = ((core::f64::consts::PI - PI as f64 - pi_mid as f64)
+                        + approx::PI_LO) as $scalar;
+                    let n = (self * Self::splat(FRAC_1_PI)).floor();
+                    let k = n + Self::splat(0.5);
+                    let r = (-k).mul_add(Self::splat(PI), self);
+                    let r = (-k).mul_add(Self::splat(pi_mid), r);
+                    let r = (-k).mul_add(Self::splat(pi_lo), r);
+
+                    // `cos((n + 1 / 2) * π + r) = -sin(n * π + r) = -(-1)^n * sin(r)`.
+                    let sin_r = r.sin_approx(if $scalar::MANTISSA_DIGITS > 24 { 13 } else { 7 });
+                    let half = Self::splat(0.5);
+                    let odd = SimdFloat::abs((n * half).fract()).simd_eq(half);
+                    odd.select(sin_r, -sin_r)
+                }
+                AccuracyMode::Default => self.cos(),
+                AccuracyMode::Precise => self.cast::<f64>().cos().cast(),
+            }
+        }
+
+        #[inline]
+        fn tanh_approx(self) -> Self {
+            // The approximant passes `1.0` just below `5.0`, so clamping the argument to `±5.0`
+            // and the result to `±1.0` saturates large elements, and keeps the powers of `x`
+            // below from overflowing. Comparisons leave `NaN` unclamped.
+            let limit = Self::splat(5.0);
+            let x = SimdFloat::abs(self)
+                .simd_gt(limit)
+                .select(limit.copysign(self), self);
+
+            let z = x * x;
+            let num = z + Self::splat(378.0);
+            let num = num.mul_add(z, Self::splat(17325.0));
+            let num = num.mul_add(z, Self::splat(135135.0));
+            let den = z.mul_add(Self::splat(28.0), Self::splat(3150.0));
+            let den = den.mul_add(z, Self::splat(62370.0));
+            let den = den.mul_add(z, Self::splat(135135.0));
+            let tanh = x * num / den;
+
+            let one = Self::splat(1.0);
+            SimdFloat::abs(tanh).simd_gt(one).select(one.copysign(self), tanh)
+        }
+
+        #[inline]
+        fn powf(self, n: Self) -> Self {
+            let one = Self::splat(1.0);
+            let pow = (n * SimdFloat::abs(self).ln()).exp();
+
+            let integer = n.simd_eq(n.trunc());
+            let half = Self::splat(0.5);
+            let odd = integer & SimdFloat::abs((n * half).fract()).simd_eq(half);
+            let pow = (self.is_sign_negative() & odd).select(-pow, pow);
+            let finite_negative = self.simd_lt(Self::splat(0.0)) & self.is_finite();
+            let pow = (finite_negative & !integer).select(Self::splat($scalar::NAN), pow);
+            // `ln(1)` is zero, and multiplying it by an infinite exponent would give `NaN`.
+            let unit = (self.simd_eq(-one) & n.is_infinite()) | self.simd_eq(one);
+            (unit | n.simd_eq(Self::splat(0.0))).select(one, pow)
+        }
+
+        #[inline]
+        fn powi(self, n: Self::Exponent) -> Self {
+            // `i32::MIN` wraps to itself, which is the right magnitude once reinterpreted.
+            let mut e = n.abs().cast::<u32>().cast::<$bits>();
+            let zero = Simd::<$bits, N>::splat(0);
+            let mut base = self;
+            let mut pow = Self::splat(1.0);
+            while e.simd_ne(zero).any() {
+                pow = (e & Simd::splat(1)).simd_ne(zero).select(pow * base, pow);
+                base *= base;
+                e >>= 1;
+            }
+            let negative = n.cast::<$scalar>().simd_lt(Self::splat(0.0));
+            negative.select(Self::splat(1.0) / pow, pow)
+        }
+
+        #[inline]
+        fn ldexp(self, exp: Self::Exponent) -> Self {
+            let max = Self::splat(($scalar::MAX_EXP - 1) as $scalar);
+            let min = Self::splat(($scalar::MIN_EXP - 1) as $scalar);
+            let digits = Self::splat($scalar::MANTISSA_DIGITS as $scalar);
+            let pow2 = |k: Self| {
+                let shift = Simd::splat($scalar::MANTISSA_DIGITS as $bits - 1);
+                Self::from_bits((k + max).cast::<$bits>() << shift)
+            };
+
+            // As in musl's `scalbn`, scale by the largest or smallest normal power of two until
+            // the remaining exponent is normal, at most twice, beyond which the result saturates.
+            // Scaling down stops `digits` short of the smallest normal exponent, so that only the
+            // last multiplication can round into the subnormals. The exponent is handled as a
+            // float, which is exact wherever it is not saturated anyway.
+            let (mut x, mut n) = (self, exp.cast::<$scalar>());
+            for _ in 0..2 {
+                let big = n.simd_gt(max);
+                x = big.select(x * pow2(max), x);
+                n = big.select(n - max, n);
+                let small = n.simd_lt(min);
+                x = small.select(x * pow2(min + digits), x);
+                n = small.select(n - (min + digits), n);
+            }
+            x * pow2(n.simd_max(min).simd_min(max))
+        }
+
+        #[inline]
+        fn powf_real(self, exp: Self) -> Self {
+            let k = (Self::splat(1.0) / exp).round();
+            let odd_root = self.simd_lt(Self::splat(0.0))
+                & (Self::splat(1.0) / k).simd_eq(exp)
+                & SimdFloat::abs((k * Self::splat(0.5)).fract()).simd_eq(Self::splat(0.5));
+            // An odd root of a negative number is the negated root of its magnitude.
+            let base = odd_root.select(-self, self);
+            let powf = scalar_fn!($scalar::powf | pow);
+            let pow = Self::from_array(core::array::from_fn(|i| powf(base[i], exp[i])));
+            odd_root.select(-pow, pow)
+        }
+
+        #[inline]
+        fn catmull_rom(p0: Self, p1: Self, p2: Self, p3: Self, t: Self) -> Self {
+            let splat = Self::splat;
+            let a = splat(3.0) * (p1 - p2) + p3 - p0;
+            let b = splat(2.0) * p0 - splat(5.0) * p1 + splat(4.0) * p2 - p3;
+            let c = p2 - p0;
+            let d = splat(2.0) * p1;
+            splat(0.5) * a.mul_add(t, b).mul_add(t, c).mul_add(t, d)
+        }
+
+        #[inline]
+        fn bilerp(c00: Self, c10: Self, c01: Self, c11: Self, tx: Self, ty: Self) -> Self {
+            let lerp = |a: Self, b: Self, t: Self| t.mul_add(b, (Self::splat(1.0) - t) * a);
+            lerp(lerp(c00, c10, tx), lerp(c01, c11, tx), ty)
+        }
+
+        #[inline]
+        fn wrap_pi(self) -> Self {
+            use core::$scalar::consts::{PI, TAU};
+
+            // `2π` is split into three parts as in `sin_approx`, so that large angles are
+            // reduced accurately.
+            let tau_mid = 2.0 * (core::f64::consts::PI - PI as f64) as $scalar;
+            let tau_lo = 2.0
+                * ((core::f64::consts::PI - PI as f64 - (tau_mid / 2.0) as f64) + approx::PI_LO)
+                    as $scalar;
+            // Ties round down, so that `π` itself is left as is.
+            let k = (self * Self::splat(1.0 / TAU) - Self::splat(0.5)).ceil();
+            let r = (-k).mul_add(Self::splat(TAU), self);
+            let r = (-k).mul_add(Self::splat(tau_mid), r);
+            let r = (-k).mul_add(Self::splat(tau_lo), r);
+
+            // Rounding `k` may leave `r` just outside of the range.
+            let r = r.simd_gt(Self::splat(PI)).select(r - Self::splat(TAU), r);
+            r.simd_lt(Self::splat(-PI)).select(r + Self::splat(TAU), r)
+        }
+
+        #[inline]
+        fn angle_diff(self, other: Self) -> Self {
+            (self - other).wrap_pi()
+        }
+
+        #[inline]
+        fn lerp_angle(self, other: Self, t: Self) -> Self {
+            let diff = other.angle_diff(self);
+            // Step from whichever end is closer, so that both ends are reached exactly.
+            let from_self = t.mul_add(diff, self);
+            let from_other = (t - Self::splat(1.0)).mul_add(diff, other);
+            t.simd_lt(Self::splat(0.5)).select(from_self, from_other).wrap_pi()
+        }
+
+        #[inline]
+        fn wrap_range(self, lo: Self, hi: Self) -> Self {
+            let zero = Self::splat(0.0);
+            let width = hi - lo;
+            // The remainder is exact and has the sign of `self - lo`.
+            let r = (self - lo) % width;
+            let r = r.simd_lt(zero).select(r + width, r);
+            let wrapped = lo + r;
+            // Both the addition above and the one to `lo` may round up to `hi`.
+            let wrapped = wrapped.simd_ge(hi).select(lo, wrapped);
+            (width.simd_eq(zero) & !self.is_nan()).select(lo, wrapped)
+        }
+
+        #[inline]
+        fn add_clamped(self, rhs: Self, min: Self, max: Self) -> Self {
+            StdFloat::clamp(self + rhs, min, max)
+        }
+
+        #[inline]
+        fn exp_clamped(self, max: Self) -> Self {
+            // Unlike `simd_min`, this keeps a `NaN` from `exp`.
+            let exp = self.exp();
+            exp.simd_gt(max).select(max, exp)
+        }
+
+        #[inline]
+        fn round_half_up(self) -> Self {
+            // `self - floor` is exact, so this only rounds up from exactly one half or more.
+            let floor = self.floor();
+            let rounded = (self - floor)
+                .simd_ge(Self::splat(0.5))
+                .select(floor + Self::splat(1.0), floor);
+            rounded.copysign(self)
+        }
+
+        #[inline]
+        fn round_half_down(self) -> Self {
+            let ceil = self.ceil();
+            let rounded = (ceil - self)
+                .simd_ge(Self::splat(0.5))
+                .select(ceil - Self::splat(1.0), ceil);
+            rounded.copysign(self)
+        }
+    }
+}
+
 macro_rules! impl_float {
     {
-        $($fn:ident: $intrinsic:ident,)*
+        $($fn:ident: $intrinsic:ident | $libm:ident,)*
     } => {
         impl<const N: usize> StdFloat for Simd<f32, N>
         where
             LaneCount<N>: SupportedLaneCount,
         {
-            #[inline]
-            fn fract(self) -> Self {
-                self - self.trunc()
-            }
+            impl_float_common! { f32, u32 }
 
             $(
             #[inline]
             fn $fn(self) -> Self {
-                unsafe { intrinsics::$intrinsic(self) }
+                #[cfg(feature = "libm_fallback")]
+                {
+                    Self::from_array(self.to_array().map(libm::Libm::<f32>::$libm))
+                }
+
+                #[cfg(not(feature = "libm_fallback"))]
+                {
+                    unsafe { intrinsics::$intrinsic(self) }
+                }
             }
             )*
         }
@@ -168,16 +1543,18 @@ impl<const N: usize> StdFloat for Simd<f64, N>
         where
             LaneCount<N>: SupportedLaneCount,
         {
-            #[inline]
-            fn fract(self) -> Self {
-                self - self.trunc()
-            }
+            impl_float_common! { f64, u64 }
 
             $(
             #[inline]
             fn $fn(self) -> Self {
+                #[cfg(feature = "libm_fallback")]
+                {
+                    Self::from_array(self.to_array().map(libm::Libm::<f64>::$libm))
+                }
+
                 // https://github.com/llvm/llvm-project/issues/83729
-                #[cfg(target_arch = "aarch64")]
+                #[cfg(all(not(feature = "libm_fallback"), target_arch = "aarch64"))]
                 {
                     let mut ln = Self::splat(0f64);
                     for i in 0..N {
@@ -186,7 +1563,7 @@ fn $fn(self) -> Self {
                     ln
                 }
 
-                #[cfg(not(target_arch = "aarch64"))]
+                #[cfg(all(not(feature = "libm_fallback"), not(target_arch = "aarch64")))]
                 {
                     unsafe { intrinsics::$intrinsic(self) }
                 }
@@ -196,12 +1573,14 @@ fn $fn(self) -> Self {
     }
 }
 
+// Each method, with the intrinsic it calls and the `libm` function that replaces it with the
+// `libm_fallback` feature.
 impl_float! {
-    sin: simd_fsin,
-    cos: simd_fcos,
-    exp: simd_fexp,
-    exp2: simd_fexp2,
-    ln: simd_flog,
-    log2: simd_flog2,
-    log10: simd_flog10,
+    sin: simd_fsin | sin,
+    cos: simd_fcos | cos,
+    exp: simd_fexp | exp,
+    exp2: simd_fexp2 | exp2,
+    ln: simd_flog | log,
+    log2: simd_flog2 | log2,
+    log10: simd_flog10 | log10,
 }
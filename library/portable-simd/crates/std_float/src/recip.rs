@@ -0,0 +1,78 @@
+//! Initial estimates of reciprocals for `StdFloat::recip_approx`, which refines them with
+//! Newton-Raphson steps.
+//!
+//! Each step squares the relative error of the estimate, so the number of steps depends on how
+//! good the estimate is. On x86 with SSE, `f32` uses the `rcpps` instruction, whose estimate has
+//! a relative error of at most `1.5 * 2^-12` for normal elements. Otherwise, the estimate is read
+//! off the bits of the element, by subtracting them from a constant chosen to minimize the
+//! maximum relative error, which is about `0.05`.
+
+use super::simd::{num::SimdFloat, LaneCount, Simd, SupportedLaneCount};
+
+pub(crate) trait RecipEstimate {
+    /// The number of Newton-Raphson steps needed to refine [`estimate`](Self::estimate) to within
+    /// a few units in the last place.
+    const STEPS: u32;
+
+    /// Estimates the reciprocal of each normal element whose reciprocal is also normal.
+    fn estimate(self) -> Self;
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse"
+))]
+impl<const N: usize> RecipEstimate for Simd<f32, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    const STEPS: u32 = 1;
+
+    #[inline]
+    fn estimate(self) -> Self {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::_mm_rcp_ps;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::_mm_rcp_ps;
+
+        // `rcpps` takes four elements at a time, so pad the last chunk with ones.
+        let x = self.to_array();
+        let mut y = [0.0; N];
+        for (x, y) in x.chunks(4).zip(y.chunks_mut(4)) {
+            let mut chunk = [1.0; 4];
+            chunk[..x.len()].copy_from_slice(x);
+            // SAFETY: SSE is enabled for the target.
+            let r: Simd<f32, 4> = unsafe { _mm_rcp_ps(Simd::from_array(chunk).into()) }.into();
+            y.copy_from_slice(&r.as_array()[..y.len()]);
+        }
+        Simd::from_array(y)
+    }
+}
+
+#[cfg(not(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse"
+)))]
+impl<const N: usize> RecipEstimate for Simd<f32, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    const STEPS: u32 = 3;
+
+    #[inline]
+    fn estimate(self) -> Self {
+        Self::from_bits(Simd::splat(0x7EF3_11C3) - self.to_bits())
+    }
+}
+
+impl<const N: usize> RecipEstimate for Simd<f64, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    const STEPS: u32 = 4;
+
+    #[inline]
+    fn estimate(self) -> Self {
+        Self::from_bits(Simd::splat(0x7FDE_6238_22FC_16E6) - self.to_bits())
+    }
+}
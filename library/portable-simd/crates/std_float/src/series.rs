@@ -0,0 +1,33 @@
+//! Evaluation of series expansions, for implementing special functions.
+
+use super::{
+    simd::{LaneCount, Simd, SupportedLaneCount},
+    StdFloat,
+};
+
+/// Evaluates the Chebyshev series `coeffs[0] * T₀(x) + coeffs[1] * T₁(x) + ...` at each element
+/// of `x`, where `Tₖ` is the Chebyshev polynomial of the first kind of degree `k`.
+///
+/// `x` is assumed to be already mapped to `[-1, 1]`, the interval over which Chebyshev
+/// expansions are usually fitted and over which each `Tₖ` is bounded by one. This is not
+/// checked; outside of it, the terms grow quickly with the degree.
+///
+/// The series is evaluated with the Clenshaw recurrence, with each step fused into a
+/// multiply-add, which is stable over `[-1, 1]`. Empty `coeffs` produce zero.
+#[inline]
+#[must_use]
+pub fn cheby_eval<const N: usize>(x: Simd<f64, N>, coeffs: &[f64]) -> Simd<f64, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    let Some((&c0, coeffs)) = coeffs.split_first() else {
+        return Simd::splat(0.0);
+    };
+    // `b[k] = 2x * b[k + 1] - b[k + 2] + c[k]`, down to `k = 1`.
+    let two_x = x + x;
+    let (mut b1, mut b2) = (Simd::splat(0.0), Simd::splat(0.0));
+    for &c in coeffs.iter().rev() {
+        (b1, b2) = (two_x.mul_add(b1, Simd::splat(c) - b2), b1);
+    }
+    x.mul_add(b1, Simd::splat(c0) - b2)
+}
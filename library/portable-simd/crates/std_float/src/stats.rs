@@ -0,0 +1,153 @@
+//! Horizontal statistics over the elements of a vector.
+
+use super::simd::{
+    cmp::SimdPartialOrd, num::SimdFloat, LaneCount, Mask, Simd, SupportedLaneCount, Swizzle,
+};
+
+/// Returns the arithmetic mean of the elements of `v`.
+#[inline]
+#[must_use]
+pub fn mean<const N: usize>(v: Simd<f64, N>) -> f64
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    v.reduce_sum() / N as f64
+}
+
+/// Returns the population variance of the elements of `v`.
+///
+/// The lanes are treated as the whole population, so the sum of squared deviations is divided
+/// by `N`, not by `N - 1` as the unbiased sample estimator would.
+///
+/// This uses the corrected two-pass algorithm: the mean is subtracted from every element before
+/// squaring, and the rounding error of that mean is then compensated for. Unlike the textbook
+/// `E[x²] - E[x]²` formula, this does not lose all precision to cancellation when the data has a
+/// large common offset.
+#[inline]
+#[must_use]
+pub fn variance<const N: usize>(v: Simd<f64, N>) -> f64
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    let n = N as f64;
+    let dev = v - Simd::splat(mean(v));
+    let err = dev.reduce_sum();
+    ((dev * dev).reduce_sum() - err * err / n) / n
+}
+
+/// Returns the number of elements of `v` that are `NaN`.
+#[inline]
+#[must_use]
+pub fn count_nan<const N: usize>(v: Simd<f64, N>) -> usize
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    // There are at most 64 lanes, so the bitmask holds every lane.
+    v.is_nan().to_bitmask().count_ones() as usize
+}
+
+/// Returns the number of elements of `v` that are neither infinite nor `NaN`.
+#[inline]
+#[must_use]
+pub fn count_finite<const N: usize>(v: Simd<f64, N>) -> usize
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    v.is_finite().to_bitmask().count_ones() as usize
+}
+
+/// Returns the elements of `v` sorted in ascending order.
+///
+/// The lanes are sorted with an odd-even transposition network, which performs the same fixed
+/// sequence of `N` vector compare-exchange steps regardless of the input.
+///
+/// A `NaN` compares neither less nor greater than any other element, so it is never exchanged
+/// with its neighbours. All elements are still present in the output, but if `v` contains a
+/// `NaN` the order of the output is unspecified.
+#[inline]
+#[must_use]
+pub fn reduce_sorted<const N: usize>(v: Simd<f64, N>) -> [f64; N]
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    let mut v = v;
+    for round in 0..N {
+        v = if round % 2 == 0 {
+            compare_exchange::<false, N>(v)
+        } else {
+            compare_exchange::<true, N>(v)
+        };
+    }
+    v.to_array()
+}
+
+/// Returns the median of the elements of `v`.
+///
+/// If `N` is even, this is the mean of the two middle elements.
+///
+/// The result is unspecified if `v` contains a `NaN`.
+#[inline]
+#[must_use]
+pub fn reduce_median<const N: usize>(v: Simd<f64, N>) -> f64
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    let sorted = reduce_sorted(v);
+    if N % 2 == 0 {
+        (sorted[N / 2 - 1] + sorted[N / 2]) / 2.0
+    } else {
+        sorted[N / 2]
+    }
+}
+
+/// Pairs up neighbouring lanes for one round of an odd-even transposition sort.
+///
+/// Even rounds pair lanes `(0, 1), (2, 3), ...` and odd rounds pair lanes `(1, 2), (3, 4), ...`.
+/// A lane without a neighbour to pair with is paired with itself.
+struct Neighbour<const ODD: bool>;
+
+impl<const ODD: bool, const N: usize> Swizzle<N> for Neighbour<ODD> {
+    const INDEX: [usize; N] = {
+        let mut index = [0; N];
+        let mut i = 0;
+        while i < N {
+            index[i] = if is_lower::<ODD>(i) {
+                if i + 1 < N {
+                    i + 1
+                } else {
+                    i
+                }
+            } else if i > 0 {
+                i - 1
+            } else {
+                i
+            };
+            i += 1;
+        }
+        index
+    };
+}
+
+/// Whether lane `i` is the lower lane of its pair in an even or odd round.
+const fn is_lower<const ODD: bool>(i: usize) -> bool {
+    (i % 2 == 0) != ODD
+}
+
+/// Orders every pair of lanes for one round of the sorting network.
+#[inline]
+fn compare_exchange<const ODD: bool, const N: usize>(v: Simd<f64, N>) -> Simd<f64, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    let neighbour = Neighbour::<ODD>::swizzle(v);
+    let mut lower = [false; N];
+    for (i, lower) in lower.iter_mut().enumerate() {
+        *lower = is_lower::<ODD>(i);
+    }
+    // The lower lane of a pair takes the smaller element, the upper lane the larger. Using
+    // comparisons rather than `simd_min`/`simd_max` keeps a `NaN` in place instead of dropping
+    // it in favour of a duplicate of its neighbour.
+    let lower = Mask::from_array(lower);
+    let exchange = (lower & v.simd_gt(neighbour)) | (!lower & v.simd_lt(neighbour));
+    exchange.select(neighbour, v)
+}
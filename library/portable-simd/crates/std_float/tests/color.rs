@@ -0,0 +1,39 @@
+#![feature(portable_simd)]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+use core_simd::simd::{f32x4, num::SimdFloat};
+use std_float::mix_srgb;
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn endpoints_are_exact() {
+    test_helpers::test_2(&|a: [f32; 4], b: [f32; 4]| {
+        let a = f32x4::from_array(a.map(|x| x.abs() % 1.0));
+        let b = f32x4::from_array(b.map(|x| x.abs() % 1.0));
+        assert_eq!(mix_srgb(a, b, f32x4::splat(0.0)).to_bits(), a.to_bits());
+        assert_eq!(mix_srgb(a, b, f32x4::splat(1.0)).to_bits(), b.to_bits());
+        Ok(())
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn blends_in_linear_light() {
+    let black = f32x4::from_array([0.0, 0.0, 0.0, 0.0]);
+    let white = f32x4::from_array([1.0, 1.0, 1.0, 1.0]);
+    let mid = mix_srgb(black, white, f32x4::splat(0.5));
+    // Half of the light of white is encoded as about 0.735, much brighter than the naive
+    // midpoint of the encoded values. Alpha is blended directly.
+    let expected = f32x4::from_array([0.735_356_6, 0.735_356_6, 0.735_356_6, 0.5]);
+    assert!((mid - expected).abs().reduce_max() <= 1e-5, "{mid:?}");
+
+    // Both the linear segment near black and the power segment are decoded.
+    let dark = f32x4::from_array([0.02, 0.5, 0.9, 1.0]);
+    let same = mix_srgb(dark, dark, f32x4::splat(0.5));
+    assert!((same - dark).abs().reduce_max() <= 1e-6, "{same:?}");
+}
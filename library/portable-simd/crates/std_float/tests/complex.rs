@@ -0,0 +1,156 @@
+#![feature(portable_simd)]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+use core_simd::simd::{
+    cmp::SimdPartialOrd, f32x2, f32x4, num::SimdFloat, LaneCount, Simd, SupportedLaneCount,
+};
+use std_float::{complex_abs, complex_mul, polar_to_complex};
+
+/// Multiplies the complex numbers `a` and `b` with the same roundings as `complex_mul`.
+fn scalar_mul(a: [f32; 2], b: [f32; 2]) -> [f32; 2] {
+    [
+        (-a[1]).mul_add(b[1], a[0] * b[0]),
+        a[1].mul_add(b[0], a[0] * b[1]),
+    ]
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn complex_mul_known() {
+    let i = f32x2::from_array([0.0, 1.0]);
+    assert_eq!(complex_mul(i, i), f32x2::from_array([-1.0, 0.0]));
+
+    // (1 + 2i)(3 + 4i) = -5 + 10i and (2 - i)(2 + i) = 5.
+    let a = f32x4::from_array([1.0, 2.0, 2.0, -1.0]);
+    let b = f32x4::from_array([3.0, 4.0, 2.0, 1.0]);
+    assert_eq!(complex_mul(a, b), f32x4::from_array([-5.0, 10.0, 5.0, 0.0]));
+    assert_eq!(complex_mul(b, a), complex_mul(a, b));
+
+    let one = f32x4::from_array([1.0, 0.0, 1.0, 0.0]);
+    assert_eq!(complex_mul(a, one), a);
+}
+
+fn matches_scalar<const N: usize>()
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    test_helpers::test_2(&|a: [f32; N], b: [f32; N]| {
+        let product = complex_mul(Simd::from_array(a), Simd::from_array(b));
+        for k in (0..N).step_by(2) {
+            let expected = scalar_mul([a[k], a[k + 1]], [b[k], b[k + 1]]);
+            for (x, y) in product[k..k + 2].iter().zip(expected) {
+                assert!(
+                    x.to_bits() == y.to_bits() || x.is_nan() && y.is_nan(),
+                    "{x} != {y}"
+                );
+            }
+        }
+        Ok(())
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn complex_mul_matches_scalar() {
+    matches_scalar::<2>();
+    matches_scalar::<4>();
+    matches_scalar::<8>();
+    matches_scalar::<16>();
+    matches_scalar::<64>();
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn complex_abs_known() {
+    // Within two units in the last place.
+    let z = f32x4::from_array([3.0, 4.0, -5.0, 12.0]);
+    let expected = f32x4::from_array([5.0, 5.0, 13.0, 13.0]);
+    let error = (complex_abs(z) - expected).abs();
+    assert!(error
+        .simd_le(expected * f32x4::splat(2.0 * f32::EPSILON))
+        .all());
+
+    let z = f32x4::from_array([0.0, -2.0, f32::NEG_INFINITY, f32::NAN]);
+    assert_eq!(
+        complex_abs(z),
+        f32x4::from_array([2.0, 2.0, f32::INFINITY, f32::INFINITY])
+    );
+
+    // Large parts don't overflow.
+    let z = f32x2::from_array([3e30, 4e30]);
+    assert_eq!(complex_abs(z), f32x2::splat(5e30));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn polar_to_complex_known() {
+    use core::f32::consts::{FRAC_PI_2, PI};
+
+    let r = f32x4::from_array([2.0, 0.0, 3.0, 0.0]);
+    let theta = f32x4::from_array([0.0, 0.0, PI, 0.0]);
+    let z = polar_to_complex(r, theta);
+    assert_eq!(z[0], 2.0);
+    assert_eq!(z[1], 0.0);
+    assert_eq!(z[2], -3.0);
+    assert!(z[3].abs() <= 3.0 * f32::EPSILON);
+
+    let z = polar_to_complex(f32x2::splat(1.0), f32x2::splat(FRAC_PI_2));
+    assert!(z[0].abs() <= f32::EPSILON);
+    assert_eq!(z[1], 1.0);
+}
+
+fn polar_round_trip<const N: usize>()
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    // Odd lanes are ignored, so fill them with garbage.
+    let r = Simd::<f32, N>::from_array(core::array::from_fn(|i| {
+        if i % 2 == 0 {
+            0.5 + i as f32
+        } else {
+            f32::NAN
+        }
+    }));
+    let theta = Simd::<f32, N>::from_array(core::array::from_fn(|i| {
+        if i % 2 == 0 {
+            i as f32 * 0.7 - 3.0
+        } else {
+            -1e30
+        }
+    }));
+    let z = polar_to_complex(r, theta);
+    let abs = complex_abs(z);
+    for k in (0..N).step_by(2) {
+        for magnitude in [abs[k], abs[k + 1]] {
+            let error = (magnitude - r[k]).abs();
+            assert!(
+                error <= 4.0 * r[k] * f32::EPSILON,
+                "{magnitude} != {}",
+                r[k]
+            );
+        }
+        // The argument comes back in `[-π, π]`.
+        let arg = z[k + 1].atan2(z[k]);
+        let turns = (arg - theta[k]) / core::f32::consts::TAU;
+        assert!(
+            (turns - turns.round()).abs() <= 1e-5,
+            "{arg} != {}",
+            theta[k]
+        );
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn polar_to_complex_round_trip() {
+    polar_round_trip::<2>();
+    polar_round_trip::<4>();
+    polar_round_trip::<8>();
+    polar_round_trip::<16>();
+    polar_round_trip::<64>();
+}
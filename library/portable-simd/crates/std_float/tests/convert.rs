@@ -0,0 +1,182 @@
+#![feature(f16, portable_simd)]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+use core_simd::simd::{f32x4, f64x4, i32x4, i64x4, u16x4, u32x4, u64x4};
+use std_float::{from_f16_bits, from_i32, from_i64, from_u32, from_u64, to_f16_bits};
+
+macro_rules! elementwise_test {
+    { $($name:ident: $int:ty => $float:ty,)* } => {
+        test_helpers::test_lanes! {
+            $(
+            fn $name<const LANES: usize>() {
+                test_helpers::test_unary_elementwise(
+                    &std_float::$name::<LANES>,
+                    &|x: $int| x as $float,
+                    &|_| true,
+                )
+            }
+            )*
+        }
+    }
+}
+
+elementwise_test! {
+    from_i32: i32 => f32,
+    from_u32: u32 => f32,
+    from_i64: i64 => f64,
+    from_u64: u64 => f64,
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn rounds_to_nearest_even() {
+    // 2^24 + 1 and 2^53 + 1 are halfway between two floats and round to the even mantissa,
+    // while 2^24 + 3 and 2^53 + 3 round up.
+    assert_eq!(
+        from_i32(i32x4::from_array([
+            (1 << 24) + 1,
+            (1 << 24) + 3,
+            -(1 << 24) - 1,
+            i32::MIN
+        ])),
+        f32x4::from_array([16777216.0, 16777220.0, -16777216.0, -2147483648.0]),
+    );
+    assert_eq!(
+        from_u32(u32x4::from_array([
+            (1 << 24) + 1,
+            (1 << 24) + 3,
+            u32::MAX,
+            0
+        ])),
+        f32x4::from_array([16777216.0, 16777220.0, 4294967296.0, 0.0]),
+    );
+    assert_eq!(
+        from_i64(i64x4::from_array([
+            (1 << 53) + 1,
+            (1 << 53) + 3,
+            -(1 << 53) - 1,
+            i64::MAX
+        ])),
+        f64x4::from_array([
+            9007199254740992.0,
+            9007199254740996.0,
+            -9007199254740992.0,
+            9223372036854775808.0,
+        ]),
+    );
+    assert_eq!(
+        from_u64(u64x4::from_array([
+            (1 << 53) + 1,
+            (1 << 53) + 3,
+            u64::MAX,
+            0
+        ])),
+        f64x4::from_array([
+            9007199254740992.0,
+            9007199254740996.0,
+            18446744073709551616.0,
+            0.0
+        ]),
+    );
+}
+
+test_helpers::test_lanes! {
+    fn to_f16_bits<const LANES: usize>() {
+        test_helpers::test_unary_elementwise(
+            &std_float::to_f16_bits::<LANES>,
+            // A `NaN` loses its payload.
+            &|x: f32| {
+                if x.is_nan() {
+                    0x7e00 | (x.to_bits() >> 16) as u16 & 0x8000
+                } else {
+                    (x as f16).to_bits()
+                }
+            },
+            &|_| true,
+        )
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn f16_rounding() {
+    let to_f16 = |x: f32| to_f16_bits(f32x4::splat(x))[0];
+    // 1 + 2^-11 is halfway between 1 and the next half precision float, and rounds to the even
+    // mantissa, while anything above it rounds up.
+    assert_eq!(to_f16(1.0 + 2.0f32.powi(-11)), 0x3c00);
+    assert_eq!(to_f16(1.0 + 2.0f32.powi(-11) + 2.0f32.powi(-20)), 0x3c01);
+    assert_eq!(to_f16(1.0 + 3.0 * 2.0f32.powi(-11)), 0x3c02);
+    assert_eq!(to_f16(-1.0 - 3.0 * 2.0f32.powi(-11)), 0xbc02);
+    // 65504 is the largest finite half precision float, and 65520 is halfway to the next power
+    // of two.
+    assert_eq!(to_f16(65504.0), 0x7bff);
+    assert_eq!(to_f16(65519.996), 0x7bff);
+    assert_eq!(to_f16(65520.0), 0x7c00);
+    assert_eq!(to_f16(-1e10), 0xfc00);
+    assert_eq!(to_f16(f32::INFINITY), 0x7c00);
+    assert_eq!(to_f16(f32::NEG_INFINITY), 0xfc00);
+    // Subnormal results, in units of 2^-24.
+    assert_eq!(to_f16(2.0f32.powi(-24)), 0x0001);
+    assert_eq!(to_f16(3.0 * 2.0f32.powi(-25)), 0x0002);
+    assert_eq!(to_f16(2.0f32.powi(-25)), 0x0000);
+    assert_eq!(to_f16(-2.0f32.powi(-25) * 1.5), 0x8001);
+    assert_eq!(to_f16(2.0f32.powi(-14) - 2.0f32.powi(-26)), 0x0400);
+    assert_eq!(to_f16(-0.0), 0x8000);
+    assert_eq!(to_f16(f32::NAN) & 0x7e00, 0x7e00);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn from_f16_bits_is_exact() {
+    for bits in 0..=u16::MAX {
+        let x = from_f16_bits(u16x4::splat(bits))[0];
+        let expected = f16::from_bits(bits) as f32;
+        if expected.is_nan() {
+            assert!(x.is_nan());
+            assert_eq!(x.is_sign_negative(), expected.is_sign_negative());
+        } else {
+            assert_eq!(x.to_bits(), expected.to_bits(), "{bits:#06x}");
+        }
+        // Every half precision float survives the round trip.
+        if !x.is_nan() {
+            assert_eq!(to_f16_bits(f32x4::splat(x))[0], bits);
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn map_f16_bits_matches_scalar() {
+    use std_float::{map_f16_bits, StdFloat};
+
+    // Every half precision float, against the scalar `f16` functions, to within a unit in the
+    // last place: the bits of floats of the same sign are ordered.
+    type Case = (&'static str, fn(f32x4) -> f32x4, fn(f16) -> f16);
+    let cases: [Case; 6] = [
+        ("sqrt", StdFloat::sqrt, f16::sqrt),
+        ("sin", StdFloat::sin, f16::sin),
+        ("cos", StdFloat::cos, f16::cos),
+        ("exp", StdFloat::exp, f16::exp),
+        ("ln", StdFloat::ln, f16::ln),
+        ("tanh", StdFloat::tanh, f16::tanh),
+    ];
+    for (name, vector, scalar) in cases {
+        for bits in 0..=u16::MAX {
+            let x = f16::from_bits(bits);
+            let result = f16::from_bits(map_f16_bits(u16x4::splat(bits), vector)[0]);
+            let expected = scalar(x);
+            if expected.is_nan() {
+                assert!(result.is_nan(), "{name}({x:?}) is not NaN");
+            } else {
+                let (a, b) = (result.to_bits(), expected.to_bits());
+                let close = (a ^ b) & 0x8000 == 0 && a.abs_diff(b) <= 1;
+                assert!(close, "{name}({x:?}): {result:?} != {expected:?}");
+            }
+        }
+    }
+}
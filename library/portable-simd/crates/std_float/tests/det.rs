@@ -0,0 +1,154 @@
+#![feature(portable_simd)]
+
+//! `sin_det` and `cos_det` promise the same bits on every target, so these compare them bitwise
+//! against results stored from a reference run, which are each within one unit in the last place
+//! of the exact result.
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// The bits of `x`, `x.sin_det()` and `x.cos_det()`, for arguments that need the reduction of
+/// Payne and Hanek, or are just below it.
+const REFERENCE_F64: [(u64, u64, u64); 13] = [
+    // 1e6
+    (0x412e848000000000, 0xbfd6664b2568d867, 0x3fedf9df9906d32c),
+    // 2^20 * π / 2, the largest argument reduced without it
+    (0x413921fb54442d18, 0xbdd1a62633145c07, 0x3ff0000000000000),
+    // 2^21
+    (0x4140000000000000, 0x3fe3f68887a137ef, 0x3fe9027224e704fa),
+    // 1e9
+    (0x41cdcd6500000000, 0x3fe1778cae83c69b, 0x3feacff8c7364234),
+    // 1e15
+    (0x430c6bf526340000, 0x3feb76f88136ceba, 0xbfe06c154609d33e),
+    // 1e22, and its negation
+    (0x4480f0cf064dd592, 0xbfeb453ab76bf397, 0x3fe0be2cef01c8f4),
+    (0xc480f0cf064dd592, 0x3feb453ab76bf397, 0x3fe0be2cef01c8f4),
+    // 6381956970095103 * 2^797, the closest `f64` to a multiple of `π / 2`
+    (0x7506ac5b262ca1ff, 0x3ff0000000000000, 0xbc214ae72e6ba22f),
+    // 1e100
+    (0x54b249ad2594c37d, 0xbfd85c5e5b929359, 0x3fed9757496841f5),
+    // 2^1000
+    (0x7e70000000000000, 0xbfc460b8ae1c886e, 0x3fef9785160c8815),
+    // 1e300
+    (0x7e37e43c8800759c, 0xbfea2c16b010e385, 0xbfe2699022adc4c1),
+    // `f64::MAX`, and `-2^1023`
+    (0x7fefffffffffffff, 0x3f7452fc98b34e97, 0xbfefffe62ecfab75),
+    (0xffe0000000000000, 0xbfe205248cbdb760, 0xbfea719f26c232bf),
+];
+
+/// As [`REFERENCE_F64`], for `f32`.
+const REFERENCE_F32: [(u32, u32, u32); 7] = [
+    // 1e6
+    (0x49742400, 0xbeb33259, 0x3f6fcefd),
+    // 2^24
+    (0x4b800000, 0xbf47917c, 0x3f2056b4),
+    // 1e10
+    (0x501502f9, 0xbef99a64, 0x3f5f84c5),
+    // 1e20
+    (0x60ad78ec, 0x3f281569, 0x3f411723),
+    // 1e30
+    (0x7149f2ca, 0xbf4a89b0, 0xbf1c9222),
+    // `f32::MAX`, and `-3e38`
+    (0x7f7fffff, 0xbf0599b3, 0x3f5a5f96),
+    (0xff61b1e6, 0xbf5ff9c4, 0xbef7f57c),
+];
+
+macro_rules! impl_det_tests {
+    { $scalar:tt, $bits:ty, $reference:ident } => {
+        mod $scalar {
+            #[cfg(target_arch = "wasm32")]
+            use wasm_bindgen_test::*;
+
+            use core_simd::simd::Simd;
+            use std_float::StdFloat;
+
+            /// Checks every argument of the reference in every lane of vectors of `N` elements,
+            /// next to the other arguments, so that neither the lane nor its neighbors change the
+            /// result.
+            fn check_reference<const N: usize>()
+            where
+                core_simd::simd::LaneCount<N>: core_simd::simd::SupportedLaneCount,
+            {
+                let reference = &super::$reference;
+                for offset in 0..reference.len() {
+                    let arg = |i: usize| reference[(i + offset) % reference.len()];
+                    let x = Simd::<$scalar, N>::from_array(core::array::from_fn(|i| {
+                        $scalar::from_bits(arg(i).0)
+                    }));
+                    let (sin, cos) = (x.sin_det(), x.cos_det());
+                    for i in 0..N {
+                        let (x, expected_sin, expected_cos) = arg(i);
+                        let x = $scalar::from_bits(x);
+                        assert_eq!(sin[i].to_bits(), expected_sin, "sin_det({x}), lane {i} of {N}");
+                        assert_eq!(cos[i].to_bits(), expected_cos, "cos_det({x}), lane {i} of {N}");
+                    }
+                }
+            }
+
+            #[test]
+            #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+            fn reference() {
+                check_reference::<1>();
+                check_reference::<4>();
+                check_reference::<8>();
+            }
+
+            #[test]
+            #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+            fn special_values() {
+                let x = Simd::<$scalar, 4>::from_array([0.0, -0.0, $scalar::MIN_POSITIVE, -1e-40]);
+                let (sin, cos) = (x.sin_det(), x.cos_det());
+                for i in 0..4 {
+                    assert_eq!(sin[i].to_bits(), x[i].to_bits(), "sin_det({})", x[i]);
+                    assert_eq!(cos[i], 1.0, "cos_det({})", x[i]);
+                }
+                let x = Simd::<$scalar, 4>::from_array([
+                    $scalar::NAN,
+                    $scalar::INFINITY,
+                    $scalar::NEG_INFINITY,
+                    1.0,
+                ]);
+                let (sin, cos) = (x.sin_det(), x.cos_det());
+                for i in 0..3 {
+                    assert!(sin[i].is_nan() && cos[i].is_nan(), "{}", x[i]);
+                }
+                assert!(sin[3].is_finite() && cos[3].is_finite());
+            }
+
+            #[test]
+            #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+            fn close_to_f64() {
+                // Arguments spaced geometrically from `1e-3` to the largest finite value, with
+                // both signs, against the scalar functions of `f64`. The results are within one
+                // unit in the last place of the exact result and the references are closer
+                // still, so the two differ by at most one unit.
+                let max = ($scalar::MAX as f64).ln();
+                let step = (max - (1e-3f64).ln()) / 4000.0;
+                for i in 0..4000 {
+                    let x = ((1e-3f64).ln() + step * i as f64).exp() as $scalar;
+                    let x = if i % 2 == 1 { -x } else { x };
+                    let x = Simd::<$scalar, 4>::splat(x);
+                    let (sin, cos) = (x.sin_det(), x.cos_det());
+                    let cases: [($scalar, fn(f64) -> f64); 2] =
+                        [(sin[0], f64::sin), (cos[0], f64::cos)];
+                    for (result, reference) in cases {
+                        let expected = reference(x[0] as f64) as $scalar;
+                        let distance = (result.to_bits() as $bits - expected.to_bits() as $bits)
+                            .unsigned_abs();
+                        assert!(
+                            distance <= 1 || (result - expected).abs() <= $scalar::MIN_POSITIVE,
+                            "{}: {result} != {expected}",
+                            x[0],
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl_det_tests! { f32, i32, REFERENCE_F32 }
+impl_det_tests! { f64, i64, REFERENCE_F64 }
@@ -0,0 +1,109 @@
+#![feature(portable_simd)]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+use core_simd::simd::{f64x4, Simd};
+use std_float::sum_of_products;
+
+/// A xorshift generator, so that the tests see the same "random" data on every run.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// A signed integer with up to `bits` bits of magnitude.
+    fn int(&mut self, bits: u32) -> i64 {
+        let magnitude = (self.next() >> (64 - bits)) as i64;
+        if self.next() & 1 == 0 {
+            magnitude
+        } else {
+            -magnitude
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sum_of_products_small() {
+    assert_eq!(sum_of_products::<4>(&[], &[]), 0.0);
+    let a = [f64x4::from_array([1.0, 2.0, 3.0, 4.0]), f64x4::splat(0.5)];
+    let b = [f64x4::from_array([4.0, 3.0, 2.0, 1.0]), f64x4::splat(-2.0)];
+    assert_eq!(sum_of_products(&a, &b), 16.0);
+    assert!(sum_of_products(&[f64x4::splat(f64::NAN)], &[f64x4::splat(1.0)]).is_nan());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+#[should_panic]
+fn sum_of_products_different_lengths() {
+    let _ = sum_of_products(&[f64x4::splat(1.0)], &[]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sum_of_products_random() {
+    // Elements are 20-bit integers scaled by powers of two in `[2^-16, 2^16]`, so every product
+    // is exact in fixed point with 32 fractional bits, and an `i128` accumulates the exact dot
+    // product for reference.
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    for len in [1, 3, 64, 1000] {
+        let mut exact = 0i128;
+        let element = |rng: &mut Rng| {
+            let (m, e) = (rng.int(20), rng.next() % 33);
+            (m, e as i32 - 16)
+        };
+        let mut a = Vec::new();
+        let mut b = Vec::new();
+        for _ in 0..len {
+            let (mut x, mut y) = ([0.0; 8], [0.0; 8]);
+            for i in 0..8 {
+                let ((mx, ex), (my, ey)) = (element(&mut rng), element(&mut rng));
+                x[i] = mx as f64 * 2f64.powi(ex);
+                y[i] = my as f64 * 2f64.powi(ey);
+                exact += (mx as i128 * my as i128) << (ex + ey + 32);
+            }
+            a.push(Simd::<f64, 8>::from_array(x));
+            b.push(Simd::from_array(y));
+        }
+        let exact = exact as f64 / 2f64.powi(32);
+        let result = sum_of_products(&a, &b);
+        assert!(
+            (result - exact).abs() <= exact.abs() * f64::EPSILON,
+            "{len}: {result} != {exact}"
+        );
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sum_of_products_cancellation() {
+    // Large products of 30-bit integers, most of them not exactly representable, that cancel out
+    // in pairs across both vectors and lanes, leaving only small ones. A naive sum keeps the
+    // rounding errors of the large products, which are far bigger than the small ones.
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    let big = |rng: &mut Rng| f64x4::from_array(core::array::from_fn(|_| rng.int(30) as f64));
+    let (mut a, mut b) = (Vec::new(), Vec::new());
+    for _ in 0..50 {
+        let (x, y) = (big(&mut rng), big(&mut rng));
+        a.extend([x, x.reverse()]);
+        b.extend([y, -y.reverse()]);
+    }
+    let mut exact = 0.0;
+    for i in 0..10 {
+        let x = f64x4::from_array(core::array::from_fn(|j| (i * 4 + j) as f64));
+        exact += x.to_array().iter().map(|x| x * 0.5).sum::<f64>();
+        let at = (i * 7) % a.len();
+        a.insert(at, x);
+        b.insert(at, f64x4::splat(0.5));
+    }
+    assert_eq!(sum_of_products(&a, &b), exact);
+}
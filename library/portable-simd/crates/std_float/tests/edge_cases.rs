@@ -0,0 +1,173 @@
+#![feature(portable_simd)]
+
+//! Feeds special values through every `StdFloat` method that has a scalar counterpart, and through
+//! `SimdFloat::signum`, and checks that each element of the result has the same bits as the scalar
+//! result.
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+macro_rules! impl_edge_case_tests {
+    { $scalar:tt } => {
+        mod $scalar {
+            #[cfg(target_arch = "wasm32")]
+            use wasm_bindgen_test::*;
+
+            use core_simd::simd::{num::SimdFloat, Simd};
+            use std_float::StdFloat;
+
+            type Vector = Simd<$scalar, 4>;
+
+            /// Signed zeros, the smallest subnormals, the largest normals, infinities, and `NaN`s
+            /// with either sign bit.
+            const SPECIAL: [$scalar; 10] = [
+                0.0,
+                -0.0,
+                $scalar::from_bits(1),
+                -$scalar::from_bits(1),
+                $scalar::MAX,
+                $scalar::MIN,
+                $scalar::INFINITY,
+                $scalar::NEG_INFINITY,
+                $scalar::NAN,
+                -$scalar::NAN,
+            ];
+
+            /// Checks that every element of `v` is `expected`. A `NaN` only has to match another
+            /// `NaN`, as neither the scalar nor the vector functions specify its sign or payload.
+            /// With the `libm_fallback` feature, a finite nonzero element only has to be within the
+            /// documented two units in the last place, as `libm` may round differently.
+            #[track_caller]
+            fn assert_same(v: Vector, expected: $scalar, name: &str, inputs: &[$scalar]) {
+                for x in v.to_array() {
+                    let ulp = expected.abs() * $scalar::EPSILON;
+                    let libm_close = cfg!(feature = "libm_fallback")
+                        && expected.is_finite()
+                        && expected != 0.0
+                        && (x - expected).abs() <= 2.0 * ulp;
+                    if expected.is_nan() {
+                        assert!(x.is_nan(), "{name}{inputs:?}: {x:?} is not NaN");
+                    } else if !libm_close {
+                        let message = format!("{name}{inputs:?}: {x:?} != {expected:?}");
+                        assert_eq!(x.to_bits(), expected.to_bits(), "{message}");
+                    }
+                }
+            }
+
+            #[test]
+            #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+            fn unary() {
+                type Case = (&'static str, fn(Vector) -> Vector, fn($scalar) -> $scalar);
+                let cases: [Case; 27] = [
+                    ("sqrt", Vector::sqrt, $scalar::sqrt),
+                    ("cbrt", Vector::cbrt, $scalar::cbrt),
+                    ("sin", Vector::sin, $scalar::sin),
+                    ("cos", Vector::cos, $scalar::cos),
+                    ("sinh", Vector::sinh, $scalar::sinh),
+                    ("cosh", Vector::cosh, $scalar::cosh),
+                    ("tanh", Vector::tanh, $scalar::tanh),
+                    ("asin", Vector::asin, $scalar::asin),
+                    ("acos", Vector::acos, $scalar::acos),
+                    ("atan", Vector::atan, $scalar::atan),
+                    ("asinh", Vector::asinh, $scalar::asinh),
+                    ("acosh", Vector::acosh, $scalar::acosh),
+                    ("atanh", Vector::atanh, $scalar::atanh),
+                    ("exp", Vector::exp, $scalar::exp),
+                    ("exp2", Vector::exp2, $scalar::exp2),
+                    ("exp_m1", Vector::exp_m1, $scalar::exp_m1),
+                    ("ln", Vector::ln, $scalar::ln),
+                    ("ln_1p", Vector::ln_1p, $scalar::ln_1p),
+                    ("log2", Vector::log2, $scalar::log2),
+                    ("log10", Vector::log10, $scalar::log10),
+                    ("ceil", Vector::ceil, $scalar::ceil),
+                    ("floor", Vector::floor, $scalar::floor),
+                    ("round", Vector::round, $scalar::round),
+                    ("round_half_away", Vector::round_half_away, $scalar::round),
+                    ("trunc", Vector::trunc, $scalar::trunc),
+                    ("fract", Vector::fract, $scalar::fract),
+                    ("signum", Vector::signum, $scalar::signum),
+                ];
+                for (name, vector, scalar) in cases {
+                    for x in SPECIAL {
+                        assert_same(vector(Vector::splat(x)), scalar(x), name, &[x]);
+                    }
+                }
+            }
+
+            #[test]
+            #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+            fn log() {
+                for x in SPECIAL {
+                    for base in SPECIAL {
+                        let v = Vector::splat(x).log(Vector::splat(base));
+                        assert_same(v, x.log(base), "log", &[x, base]);
+                    }
+                }
+            }
+
+            #[test]
+            #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+            fn atan2() {
+                for y in SPECIAL {
+                    for x in SPECIAL {
+                        let v = Vector::splat(y).atan2(Vector::splat(x));
+                        assert_same(v, y.atan2(x), "atan2", &[y, x]);
+                    }
+                }
+            }
+
+            #[test]
+            #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+            fn hypot() {
+                for x in SPECIAL {
+                    for y in SPECIAL {
+                        let v = Vector::splat(x).hypot(Vector::splat(y));
+                        assert_same(v, x.hypot(y), "hypot", &[x, y]);
+                    }
+                }
+            }
+
+            #[test]
+            #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+            fn powf() {
+                for x in SPECIAL {
+                    for n in SPECIAL {
+                        let v = Vector::splat(x).powf(Vector::splat(n));
+                        assert_same(v, x.powf(n), "powf", &[x, n]);
+                    }
+                }
+            }
+
+            #[test]
+            #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+            fn mul_add() {
+                for x in SPECIAL {
+                    for a in SPECIAL {
+                        for b in SPECIAL {
+                            let v = Vector::splat(x).mul_add(Vector::splat(a), Vector::splat(b));
+                            assert_same(v, x.mul_add(a, b), "mul_add", &[x, a, b]);
+                        }
+                    }
+                }
+            }
+
+            #[test]
+            #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+            fn add_clamped() {
+                let (min, max) = (Vector::splat(-1.0), Vector::splat(1.0));
+                for x in SPECIAL {
+                    for rhs in SPECIAL {
+                        let v = Vector::splat(x).add_clamped(Vector::splat(rhs), min, max);
+                        assert_same(v, (x + rhs).clamp(-1.0, 1.0), "add_clamped", &[x, rhs]);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl_edge_case_tests! { f32 }
+impl_edge_case_tests! { f64 }
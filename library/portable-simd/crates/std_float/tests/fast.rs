@@ -0,0 +1,130 @@
+#![feature(portable_simd)]
+
+//! `FastStdFloat` only promises accuracy for well-behaved elements, so these check finite normal
+//! elements with finite normal results against the precise functions, and nothing else.
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+macro_rules! impl_fast_tests {
+    { $scalar:tt } => {
+        mod $scalar {
+            #[cfg(target_arch = "wasm32")]
+            use wasm_bindgen_test::*;
+
+            use core_simd::simd::{num::SimdFloat, Simd};
+            use std_float::{FastStdFloat, StdFloat};
+
+            type Vector = Simd<$scalar, 8>;
+
+            /// The documented bound of `exp_fast` and `ln_fast`, in units in the last place.
+            const FAST_ULPS: f64 = if $scalar::MANTISSA_DIGITS > 24 { 1024.0 } else { 64.0 };
+
+            /// Vectors of 8 elements spaced geometrically over `[start, end]`, both positive, with
+            /// both signs if `signed`.
+            fn vectors(start: $scalar, end: $scalar, signed: bool) -> Vec<Vector> {
+                let step = (end.ln() - start.ln()) / 799.0;
+                (0..100)
+                    .map(|i| {
+                        Vector::from_array(core::array::from_fn(|j| {
+                            let x = (start.ln() + step * (i * 8 + j) as $scalar).exp();
+                            let x = x.clamp(start, end);
+                            if signed && j % 2 == 1 { -x } else { x }
+                        }))
+                    })
+                    .collect()
+            }
+
+            /// Asserts that every element of `v` is finite and within `ulps` units in the last
+            /// place of the reference in `f64`, which for `f32` is far more precise.
+            #[track_caller]
+            fn assert_close(v: Vector, x: Vector, reference: fn(f64) -> f64, ulps: f64) {
+                for i in 0..8 {
+                    let expected = reference(x[i] as f64);
+                    let ulp = (expected.abs() as $scalar).max($scalar::MIN_POSITIVE);
+                    let ulp = (ulp * $scalar::EPSILON) as f64;
+                    assert!(v[i].is_finite(), "{}: {} is not finite", x[i], v[i]);
+                    let error = (v[i] as f64 - expected).abs() / ulp;
+                    assert!(error <= ulps, "{}: {} != {expected}, {error} ulps", x[i], v[i]);
+                }
+            }
+
+            #[test]
+            #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+            fn mul_add_fast() {
+                // Either fused or not, depending on the target.
+                let eps = $scalar::EPSILON;
+                let (x, a) = (Vector::splat(1.0 + eps), Vector::splat(1.0 - eps));
+                let b = Vector::splat(-1.0);
+                let r = x.mul_add_fast(a, b);
+                assert!(r == x.mul_add(a, b) || r == x * a + b);
+                for x in vectors(1e-3, 1e3, true) {
+                    let (a, b) = (x.reverse(), x.rotate_elements_left::<3>());
+                    let r = x.mul_add_fast(a, b);
+                    for i in 0..8 {
+                        assert!(r[i] == x.mul_add(a, b)[i] || r[i] == (x * a + b)[i]);
+                    }
+                }
+            }
+
+            #[test]
+            #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+            fn recip_fast() {
+                let max = 1.0 / $scalar::MIN_POSITIVE;
+                for x in vectors($scalar::MIN_POSITIVE * 2.0, max / 2.0, true) {
+                    assert_close(x.recip_fast(), x, |x| 1.0 / x, 3.0);
+                }
+            }
+
+            #[test]
+            #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+            fn exp_fast() {
+                // Results from just above the smallest normal to just below overflow.
+                let min = ($scalar::MIN_EXP as $scalar) * core::$scalar::consts::LN_2 + 1.0;
+                let max = ($scalar::MAX_EXP as $scalar) * core::$scalar::consts::LN_2 - 1.0;
+                for x in vectors(1e-6, max, false) {
+                    assert_close(x.exp_fast(), x, f64::exp, FAST_ULPS);
+                }
+                for x in vectors(1e-6, -min, false) {
+                    assert_close((-x).exp_fast(), -x, f64::exp, FAST_ULPS);
+                }
+            }
+
+            #[test]
+            #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+            fn ln_fast() {
+                for x in vectors($scalar::MIN_POSITIVE, $scalar::MAX, false) {
+                    assert_close(x.ln_fast(), x, f64::ln, FAST_ULPS);
+                }
+                // Close to `1.0`, where the result goes to zero.
+                for x in vectors(0.5, 2.0, false) {
+                    assert_close(x.ln_fast(), x, f64::ln, FAST_ULPS);
+                }
+            }
+
+            #[test]
+            #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+            fn reductions_fast() {
+                for x in vectors(1e-3, 1e3, true) {
+                    // Both orders round each of the seven operations once.
+                    let bound = 16.0 * $scalar::EPSILON;
+                    let sum = x.reduce_sum_fast();
+                    let abs_sum = x.abs().reduce_sum();
+                    assert!((sum - x.reduce_sum()).abs() <= bound * abs_sum, "{x:?}");
+                    let product = x.reduce_product_fast();
+                    let expected = x.reduce_product();
+                    assert!((product - expected).abs() <= bound * expected.abs(), "{x:?}");
+                }
+                let x = Vector::from_array([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+                assert_eq!(x.reduce_sum_fast(), 36.0);
+                assert_eq!(x.reduce_product_fast(), 40320.0);
+            }
+        }
+    }
+}
+
+impl_fast_tests! { f32 }
+impl_fast_tests! { f64 }
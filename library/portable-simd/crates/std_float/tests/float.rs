@@ -1,4 +1,4 @@
-#![feature(portable_simd)]
+#![feature(portable_simd, float_gamma, float_minimum_maximum, num_midpoint)]
 
 macro_rules! unary_test {
     { $scalar:tt, $($func:tt),+ } => {
@@ -32,6 +32,67 @@ fn $func<const LANES: usize>() {
     }
 }
 
+/// Like `unary_test!`, for the methods that call `libm` with the `libm_fallback` feature, where
+/// they only have to be within two units in the last place of the scalar functions.
+macro_rules! libm_unary_test {
+    { $scalar:tt, $($func:tt),+ } => {
+        test_helpers::test_lanes! {
+            $(
+            fn $func<const LANES: usize>() {
+                #[cfg(not(feature = "libm_fallback"))]
+                test_helpers::test_unary_elementwise(
+                    &core_simd::simd::Simd::<$scalar, LANES>::$func,
+                    &$scalar::$func,
+                    &|_| true,
+                );
+
+                #[cfg(feature = "libm_fallback")]
+                test_helpers::test_1(&|x: [$scalar; LANES]| {
+                    let v = core_simd::simd::Simd::<$scalar, LANES>::from_array(x).$func();
+                    for i in 0..LANES {
+                        let (a, b) = (v[i], x[i].$func());
+                        let name = stringify!($func);
+                        assert!(matches_scalar(a, b), "{name}({}): {a} != {b}", x[i]);
+                    }
+                    Ok(())
+                });
+            }
+            )*
+        }
+    }
+}
+
+/// Like `binary_test!`, for the methods that call `libm` with the `libm_fallback` feature.
+macro_rules! libm_binary_test {
+    { $scalar:tt, $($func:tt),+ } => {
+        test_helpers::test_lanes! {
+            $(
+            fn $func<const LANES: usize>() {
+                #[cfg(not(feature = "libm_fallback"))]
+                test_helpers::test_binary_elementwise(
+                    &core_simd::simd::Simd::<$scalar, LANES>::$func,
+                    &$scalar::$func,
+                    &|_, _| true,
+                );
+
+                #[cfg(feature = "libm_fallback")]
+                test_helpers::test_2(&|x: [$scalar; LANES], y: [$scalar; LANES]| {
+                    use core_simd::simd::Simd;
+
+                    let v = Simd::<$scalar, LANES>::from_array(x).$func(Simd::from_array(y));
+                    for i in 0..LANES {
+                        let (a, b) = (v[i], x[i].$func(y[i]));
+                        let name = stringify!($func);
+                        assert!(matches_scalar(a, b), "{name}({}, {}): {a} != {b}", x[i], y[i]);
+                    }
+                    Ok(())
+                });
+            }
+            )*
+        }
+    }
+}
+
 macro_rules! ternary_test {
     { $scalar:tt, $($func:tt),+ } => {
         test_helpers::test_lanes! {
@@ -53,8 +114,22 @@ macro_rules! impl_tests {
         mod $scalar {
             use std_float::StdFloat;
 
-            unary_test! { $scalar, sqrt, sin, cos, exp, exp2, ln, log2, log10, ceil, floor, round, trunc }
-            binary_test! { $scalar, log }
+            /// Whether `a` matches the result `b` of a scalar function: `NaN` or the same bits, or
+            /// with the `libm_fallback` feature, within two units in the last place of a finite
+            /// nonzero `b`, as `libm` may round differently from `std`.
+            fn matches_scalar(a: $scalar, b: $scalar) -> bool {
+                let ulp = b.abs() * $scalar::EPSILON;
+                let libm_close = cfg!(feature = "libm_fallback")
+                    && b.is_finite()
+                    && b != 0.0
+                    && (a - b).abs() <= 2.0 * ulp;
+                a.is_nan() && b.is_nan() || a.to_bits() == b.to_bits() || libm_close
+            }
+
+            unary_test! { $scalar, sqrt, ceil, floor, round, trunc }
+            libm_unary_test! { $scalar, sin, cos, exp, exp2, ln, log2, log10, asin, acos, atan }
+            binary_test! { $scalar, div_euclid, rem_euclid }
+            libm_binary_test! { $scalar, atan2 }
             ternary_test! { $scalar, mul_add }
 
             test_helpers::test_lanes! {
@@ -65,6 +140,1493 @@ fn fract<const LANES: usize>() {
                         &|_| true,
                     )
                 }
+
+                fn copysign<const LANES: usize>() {
+                    use core_simd::simd::{num::SimdFloat, Simd};
+
+                    test_helpers::test_binary_elementwise(
+                        &Simd::<$scalar, LANES>::copysign,
+                        &$scalar::copysign,
+                        &|_, _| true,
+                    )
+                }
+
+                fn signum<const LANES: usize>() {
+                    use core_simd::simd::{num::SimdFloat, Simd};
+
+                    test_helpers::test_unary_elementwise(
+                        &Simd::<$scalar, LANES>::signum,
+                        &$scalar::signum,
+                        &|_| true,
+                    )
+                }
+
+                fn clamp<const LANES: usize>() {
+                    use core_simd::simd::{num::SimdFloat, Simd};
+
+                    let splat = Simd::<$scalar, LANES>::splat;
+                    let clamp = |x: $scalar, min: $scalar, max: $scalar| {
+                        StdFloat::clamp(splat(x), splat(min), splat(max))[0]
+                    };
+                    assert_eq!(clamp(-3.0, -1.0, 2.0), -1.0);
+                    assert_eq!(clamp(0.5, -1.0, 2.0), 0.5);
+                    assert_eq!(clamp(5.0, -1.0, 2.0), 2.0);
+                    assert_eq!(clamp($scalar::INFINITY, -1.0, 2.0), 2.0);
+
+                    // `NaN` in `self` propagates, and a `NaN` bound is ignored.
+                    let nan = $scalar::NAN;
+                    assert!(clamp(nan, -1.0, 2.0).is_nan());
+                    assert!(clamp(nan, nan, nan).is_nan());
+                    assert_eq!(clamp(-3.0, nan, 2.0), -3.0);
+                    assert_eq!(clamp(5.0, nan, 2.0), 2.0);
+                    assert_eq!(clamp(5.0, -1.0, nan), 5.0);
+                    assert_eq!(clamp(-3.0, -1.0, nan), -1.0);
+                    assert_eq!(clamp(5.0, nan, nan), 5.0);
+
+                    // Reversed bounds produce `max`.
+                    assert_eq!(clamp(-3.0, 2.0, -1.0), -1.0);
+                    assert_eq!(clamp(0.5, 2.0, -1.0), -1.0);
+                    assert_eq!(clamp(5.0, 2.0, -1.0), -1.0);
+
+                    // Matches the scalar `clamp` wherever its bounds are valid.
+                    type Array<const LANES: usize> = [$scalar; LANES];
+                    test_helpers::test_3(&|x: Array<LANES>, a: Array<LANES>, b: Array<LANES>| {
+                        let (a, b) = (Simd::from_array(a), Simd::from_array(b));
+                        let (min, max) = (a.simd_min(b), a.simd_max(b));
+                        let clamped = StdFloat::clamp(Simd::from_array(x), min, max);
+                        for i in (0..LANES).filter(|&i| !min[i].is_nan() && !max[i].is_nan()) {
+                            let (a, b) = (clamped[i], x[i].clamp(min[i], max[i]));
+                            let same = a == b || a.is_nan() && b.is_nan();
+                            assert!(same, "{}.clamp({}, {}): {a}", x[i], min[i], max[i]);
+                        }
+                        Ok(())
+                    });
+                }
+
+                fn euclid_signs<const LANES: usize>() {
+                    use core_simd::simd::Simd;
+
+                    // Every combination of signs, against the scalar functions.
+                    let cases = [(7.0, 4.0), (-7.0, 4.0), (7.0, -4.0), (-7.0, -4.0), (-8.0, 4.0)];
+                    for (x, y) in cases {
+                        let (a, b) = (Simd::<$scalar, LANES>::splat(x), Simd::splat(y));
+                        assert_eq!(a.div_euclid(b), Simd::splat(x.div_euclid(y)), "{x}, {y}");
+                        assert_eq!(a.rem_euclid(b), Simd::splat(x.rem_euclid(y)), "{x}, {y}");
+                    }
+                    let splat = Simd::<$scalar, LANES>::splat;
+                    assert_eq!(splat(-7.0).div_euclid(splat(4.0)), splat(-2.0));
+                    assert_eq!(splat(-7.0).rem_euclid(splat(4.0)), splat(1.0));
+                    assert_eq!(splat(-7.0).div_euclid(splat(-4.0)), splat(2.0));
+                    assert_eq!(splat(-7.0).rem_euclid(splat(-4.0)), splat(1.0));
+                    assert_eq!(splat(7.0).div_euclid(splat(-4.0)), splat(-1.0));
+                    assert_eq!(splat(7.0).rem_euclid(splat(-4.0)), splat(3.0));
+                }
+
+                fn fused_variants<const LANES: usize>() {
+                    use core_simd::simd::{num::SimdFloat, Simd};
+
+                    // `(1 + ε)(1 - ε) = 1 - ε²` exactly, which rounds to `1` before the `±1` is
+                    // applied unless there is only one rounding.
+                    let eps = $scalar::EPSILON;
+                    let splat = Simd::<$scalar, LANES>::splat;
+                    let (x, a) = (splat(1.0 + eps), splat(1.0 - eps));
+                    assert_eq!(x * a - splat(1.0), splat(0.0));
+                    assert_eq!(x.mul_sub(a, splat(1.0)), splat(-eps * eps));
+                    assert_eq!(x.neg_mul_add(a, splat(1.0)), splat(eps * eps));
+                    assert_eq!(x.neg_mul_sub(a, splat(-1.0)), splat(eps * eps));
+                    assert_eq!(x.mul_add(a, splat(-1.0)), x.mul_sub(a, splat(1.0)));
+
+                    // Signs, including those of zero results.
+                    let (two, three) = (splat(2.0), splat(3.0));
+                    assert_eq!(two.mul_sub(three, splat(1.0)), splat(5.0));
+                    assert_eq!(two.neg_mul_add(three, splat(1.0)), splat(-5.0));
+                    assert_eq!(two.neg_mul_sub(three, splat(1.0)), splat(-7.0));
+                    assert_eq!(splat(-2.0).neg_mul_sub(three, splat(1.0)), splat(5.0));
+                    let (zero, one) = (splat(0.0), splat(1.0));
+                    assert_eq!(zero.mul_sub(one, zero).to_bits(), zero.to_bits());
+                    assert_eq!(zero.neg_mul_add(one, zero).to_bits(), zero.to_bits());
+                    assert_eq!(zero.neg_mul_sub(one, zero).to_bits(), splat(-0.0).to_bits());
+
+                    // Each is `mul_add` with the signs flipped, which is exact.
+                    type Array<const LANES: usize> = [$scalar; LANES];
+                    test_helpers::test_3(&|x: Array<LANES>, a: Array<LANES>, b: Array<LANES>| {
+                        let (x, a) = (Simd::from_array(x), Simd::from_array(a));
+                        let b = Simd::from_array(b);
+                        let same = |u: Simd<$scalar, LANES>, v: Simd<$scalar, LANES>| {
+                            (0..LANES).all(|i| u[i] == v[i] || u[i].is_nan() && v[i].is_nan())
+                        };
+                        assert!(same(x.mul_sub(a, b), x.mul_add(a, -b)));
+                        assert!(same(x.neg_mul_add(a, b), (-x).mul_add(a, b)));
+                        assert!(same(x.neg_mul_sub(a, b), (-x).mul_add(a, -b)));
+                        Ok(())
+                    });
+                }
+
+                fn classification_masks<const LANES: usize>() {
+                    use core_simd::simd::{num::SimdFloat, Simd};
+
+                    // Quiet and signaling `NaN`s of either sign, infinities, and finite elements
+                    // including zeros, subnormals and the extremes, rotated through the lanes.
+                    let inf = $scalar::INFINITY;
+                    let signaling = $scalar::from_bits(inf.to_bits() | 1);
+                    let nans = [$scalar::NAN, -$scalar::NAN, signaling, -signaling];
+                    let finite = [0.0, -0.0, 1.0, -2.5, $scalar::MIN_POSITIVE / 2.0, $scalar::MAX];
+                    let cases: Vec<$scalar> =
+                        nans.into_iter().chain([inf, -inf]).chain(finite).collect();
+                    for offset in 0..cases.len() {
+                        let x = core::array::from_fn(|i| cases[(i + offset) % cases.len()]);
+                        let v = Simd::<$scalar, LANES>::from_array(x);
+                        let (nan, finite, infinite) = (v.is_nan(), v.is_finite(), v.is_infinite());
+                        for i in 0..LANES {
+                            let expected = (x[i].is_nan(), x[i].is_finite(), x[i].is_infinite());
+                            assert_eq!((nan.test(i), finite.test(i), infinite.test(i)), expected);
+                            // Exactly one of the three holds.
+                            let count = [nan, finite, infinite].map(|m| m.test(i) as u8);
+                            assert_eq!(count.iter().sum::<u8>(), 1);
+                        }
+                    }
+                }
+
+                fn sign_masks<const LANES: usize>() {
+                    use core_simd::simd::{num::SimdFloat, Simd};
+
+                    let splat = Simd::<$scalar, LANES>::splat;
+                    for (x, sign_positive) in [
+                        (0.0, true),
+                        (-0.0, false),
+                        (1.5, true),
+                        (-1.5, false),
+                        ($scalar::INFINITY, true),
+                        ($scalar::NEG_INFINITY, false),
+                        ($scalar::NAN, true),
+                        (-$scalar::NAN, false),
+                    ] {
+                        assert_eq!(splat(x).is_sign_positive().all(), sign_positive, "{x}");
+                        assert_eq!(splat(x).is_sign_negative().all(), !sign_positive, "{x}");
+                        // `copysign` copies exactly the bit that is inspected.
+                        let copied = splat(2.0).copysign(splat(x));
+                        assert_eq!(copied.is_sign_negative(), splat(x).is_sign_negative(), "{x}");
+                    }
+
+                    // Mixed lanes.
+                    let x = core::array::from_fn(|i| if i % 2 == 0 { -0.0 } else { $scalar::NAN });
+                    let mask = Simd::<$scalar, LANES>::from_array(x).is_sign_negative();
+                    assert!((0..LANES).all(|i| mask.test(i) == (i % 2 == 0)));
+                }
+
+                fn classify<const LANES: usize>() {
+                    use core::num::FpCategory;
+                    use core_simd::simd::Simd;
+
+                    // One element of each category, with either sign, rotated through the lanes.
+                    let cases = [
+                        ($scalar::NAN, FpCategory::Nan),
+                        ($scalar::from_bits($scalar::INFINITY.to_bits() | 1), FpCategory::Nan),
+                        ($scalar::INFINITY, FpCategory::Infinite),
+                        (0.0, FpCategory::Zero),
+                        ($scalar::from_bits(1), FpCategory::Subnormal),
+                        ($scalar::MIN_POSITIVE - $scalar::from_bits(1), FpCategory::Subnormal),
+                        ($scalar::MIN_POSITIVE, FpCategory::Normal),
+                        (1.0, FpCategory::Normal),
+                        ($scalar::MAX, FpCategory::Normal),
+                    ];
+                    let cases: Vec<_> =
+                        cases.iter().flat_map(|&(x, c)| [(x, c), (-x, c)]).collect();
+                    for offset in 0..cases.len() {
+                        let case = |i: usize| cases[(i + offset) % cases.len()];
+                        let x = core::array::from_fn(|i| case(i).0);
+                        let codes = Simd::<$scalar, LANES>::from_array(x).classify();
+                        for i in 0..LANES {
+                            assert_eq!(case(i).0.classify(), case(i).1);
+                            assert_eq!(codes[i], case(i).1 as i32, "{}", case(i).0);
+                        }
+                    }
+                }
+
+                fn total_cmp<const LANES: usize>() {
+                    use core_simd::simd::Simd;
+
+                    // In ascending order, including `NaN`s with different payloads.
+                    let nan = $scalar::NAN;
+                    let other_nan = $scalar::from_bits(nan.to_bits() | 1);
+                    let ordered = [
+                        -other_nan,
+                        -nan,
+                        $scalar::NEG_INFINITY,
+                        $scalar::MIN,
+                        -1.0,
+                        -$scalar::from_bits(1),
+                        -0.0,
+                        0.0,
+                        $scalar::from_bits(1),
+                        1.0,
+                        $scalar::MAX,
+                        $scalar::INFINITY,
+                        nan,
+                        other_nan,
+                    ];
+                    let splat = Simd::<$scalar, LANES>::splat;
+                    for (i, &x) in ordered.iter().enumerate() {
+                        for (j, &y) in ordered.iter().enumerate() {
+                            let expected = i.cmp(&j) as i32;
+                            assert_eq!(x.total_cmp(&y) as i32, expected);
+                            let cmp = splat(x).total_cmp(splat(y));
+                            assert_eq!(cmp, Simd::splat(expected), "{x}, {y}");
+                        }
+                    }
+
+                    test_helpers::test_2(&|x: [$scalar; LANES], y: [$scalar; LANES]| {
+                        let cmp = Simd::from_array(x).total_cmp(Simd::from_array(y));
+                        for i in 0..LANES {
+                            assert_eq!(cmp[i], x[i].total_cmp(&y[i]) as i32, "{}, {}", x[i], y[i]);
+                        }
+                        Ok(())
+                    });
+                }
+
+                fn minimum_maximum<const LANES: usize>() {
+                    use core_simd::simd::{num::SimdFloat, Simd};
+
+                    // Against the scalar functions, telling signed zeros apart.
+                    let check = |x: [$scalar; LANES], y: [$scalar; LANES]| {
+                        let (a, b) = (Simd::from_array(x), Simd::from_array(y));
+                        let (min, max) = (a.minimum(b), a.maximum(b));
+                        for i in 0..LANES {
+                            let (x, y) = (x[i], y[i]);
+                            for (a, b) in [(min[i], x.minimum(y)), (max[i], x.maximum(y))] {
+                                let same = a.to_bits() == b.to_bits() || a.is_nan() && b.is_nan();
+                                assert!(same, "{x}, {y}: {a} != {b}");
+                            }
+                        }
+                    };
+                    let inf = $scalar::INFINITY;
+                    let values = [$scalar::NAN, -$scalar::NAN, -inf, -1.0, -0.0, 0.0, 2.5, inf];
+                    for x in values {
+                        for y in values {
+                            check([x; LANES], [y; LANES]);
+                        }
+                    }
+
+                    let splat = Simd::<$scalar, LANES>::splat;
+                    let (zero, negative_zero): ($scalar, $scalar) = (0.0, -0.0);
+                    let min = splat(zero).minimum(splat(negative_zero))[0];
+                    let max = splat(negative_zero).maximum(splat(zero))[0];
+                    assert_eq!(min.to_bits(), negative_zero.to_bits());
+                    assert_eq!(max.to_bits(), zero.to_bits());
+                    assert!(splat(1.0).minimum(splat($scalar::NAN)).is_nan().all());
+                    assert!(splat($scalar::NAN).maximum(splat(1.0)).is_nan().all());
+
+                    test_helpers::test_2(&|x: [$scalar; LANES], y: [$scalar; LANES]| {
+                        check(x, y);
+                        Ok(())
+                    });
+                }
+
+                fn midpoint<const LANES: usize>() {
+                    use core_simd::simd::{num::SimdFloat, Simd};
+
+                    let splat = Simd::<$scalar, LANES>::splat;
+                    let (max, inf) = ($scalar::MAX, $scalar::INFINITY);
+                    assert_eq!(splat(1.0).midpoint(splat(4.0)), splat(2.5));
+                    assert_eq!(splat(-5.5).midpoint(splat(8.0)), splat(1.25));
+                    assert_eq!(splat(max).midpoint(splat(max)), splat(max));
+                    assert_eq!(splat(-max).midpoint(splat(-max)), splat(-max));
+                    assert_eq!(splat(max).midpoint(splat(-max)), splat(0.0));
+                    assert_eq!(splat(max).midpoint(splat(max / 2.0)), splat(max * 0.75));
+                    assert_eq!(splat(-max).midpoint(splat(1.0)), splat(-max / 2.0));
+                    assert_eq!(splat(max).midpoint(splat(inf)), splat(inf));
+                    assert!(splat(inf).midpoint(splat(-inf)).is_nan().all());
+                    assert!(splat($scalar::NAN).midpoint(splat(1.0)).is_nan().all());
+                    // Tiny elements are not halved, which would lose their lowest bit.
+                    let tiny = $scalar::from_bits(1);
+                    assert_eq!(splat(max).midpoint(splat(tiny)), splat(max / 2.0));
+                    assert_eq!(splat(tiny).midpoint(splat(tiny)), splat(tiny));
+
+                    // Against the scalar function.
+                    test_helpers::test_2(&|x: [$scalar; LANES], y: [$scalar; LANES]| {
+                        let mid = Simd::from_array(x).midpoint(Simd::from_array(y));
+                        for i in 0..LANES {
+                            let (a, b) = (mid[i], x[i].midpoint(y[i]));
+                            assert!(a == b || a.is_nan() && b.is_nan(), "{}, {}: {a}", x[i], y[i]);
+                        }
+                        Ok(())
+                    });
+                }
+
+                fn log_base<const LANES: usize>() {
+                    use core_simd::simd::{num::SimdFloat, Simd};
+
+                    // Exact for powers of two bases and for base ten, where the naive quotient
+                    // of natural logarithms is often a unit in the last place off.
+                    let splat = Simd::<$scalar, LANES>::splat;
+                    let exact = [
+                        (100.0, 10.0, 2.0),
+                        (1000.0, 10.0, 3.0),
+                        (1e-2, 10.0, -2.0),
+                        (8.0, 2.0, 3.0),
+                        (1.0 / 1024.0, 2.0, -10.0),
+                        (1048576.0, 4.0, 10.0),
+                        (512.0, 8.0, 3.0),
+                        (0.125, 0.5, 3.0),
+                        (1.0, 3.0, 0.0),
+                    ];
+                    for (x, base, log) in exact {
+                        assert_eq!(splat(x).log(splat(base)), splat(log), "log({x}, {base})");
+                    }
+
+                    // Within two units in the last place of a reference: the quotient of natural
+                    // logarithms in `f64`, which for `f32` is far more precise, or the exponent of
+                    // exact powers of the base.
+                    let within = |a: $scalar, b: f64| {
+                        let ulp = (b.abs() as $scalar).max($scalar::MIN_POSITIVE);
+                        ((a as f64 - b).abs() as $scalar) <= 2.0 * ulp * $scalar::EPSILON
+                    };
+                    for base in [0.1, 0.5, 0.9, 1.1, 3.0, 7.0, 10.0, 100.0, 1e10] {
+                        let mut x: $scalar = 1e-6;
+                        while x < 1e6 {
+                            let log = splat(x).log(splat(base))[0];
+                            let reference = (x as f64).ln() / (base as f64).ln();
+                            assert!(within(log, reference), "log({x}, {base}) = {log}");
+                            x *= 1.37;
+                        }
+                    }
+                    for base in [3.0, 5.0, 7.0, 10.0, 11.0] {
+                        let mut x: $scalar = 1.0;
+                        for k in 0..10 {
+                            let log = splat(x).log(splat(base))[0];
+                            assert!(within(log, k as f64), "log({x}, {base}) = {log}");
+                            x *= base;
+                        }
+                    }
+
+                    let (nan, inf) = ($scalar::NAN, $scalar::INFINITY);
+                    assert!(splat(-1.0).log(splat(10.0)).is_nan().all());
+                    assert!(splat(nan).log(splat(2.0)).is_nan().all());
+                    assert_eq!(splat(0.0).log(splat(10.0)), splat(-inf));
+                    assert_eq!(splat(inf).log(splat(3.0)), splat(inf));
+                }
+
+                fn gamma<const LANES: usize>() {
+                    use core_simd::simd::{num::SimdFloat, Simd};
+
+                    let splat = Simd::<$scalar, LANES>::splat;
+                    let mut factorial = 1.0;
+                    for n in 1..=10 {
+                        assert_eq!(splat(n as $scalar).gamma(), splat(factorial), "{n}");
+                        factorial *= n as $scalar;
+                    }
+                    assert_eq!(splat(5.0).gamma(), splat(24.0));
+                    assert_eq!(splat(1.0).lgamma(), splat(0.0));
+                    assert_eq!(splat(2.0).lgamma(), splat(0.0));
+
+                    // Poles and infinities.
+                    let inf = $scalar::INFINITY;
+                    assert_eq!(splat(0.0).gamma(), splat(inf));
+                    assert_eq!(splat(-0.0).gamma(), splat(-inf));
+                    assert_eq!(splat(inf).gamma(), splat(inf));
+                    for x in [-1.0, -2.0, -100.0, -inf, $scalar::NAN] {
+                        assert!(splat(x).gamma().is_nan().all(), "{x}");
+                    }
+                    for x in [0.0, -1.0, -2.0, -100.0, inf, -inf] {
+                        assert_eq!(splat(x).lgamma(), splat(inf), "{x}");
+                    }
+                    assert_eq!(splat(200.0).gamma(), splat(inf));
+
+                    // Against the `f64` scalar functions, over a spread of non-integers on both
+                    // sides of the reflection, the poles and overflow.
+                    let close = |a: $scalar, b: f64, tolerance: f64| {
+                        let (a, scale) = (a as f64, b.abs().max($scalar::MIN_POSITIVE as f64));
+                        a == b || (a - b).abs() <= tolerance * scale
+                    };
+                    let tolerance = if $scalar::MANTISSA_DIGITS > 24 { 1e-13 } else { 1.2e-7 };
+                    let mut x: $scalar = -30.05;
+                    while x < 40.0 {
+                        let (gamma, lgamma) = (splat(x).gamma()[0], splat(x).lgamma()[0]);
+                        // Rounded, so that it overflows along with `gamma`.
+                        let reference = (x as f64).gamma() as $scalar as f64;
+                        assert!(close(gamma, reference, tolerance), "gamma({x}) = {gamma}");
+                        // `lgamma` has roots at `1` and `2`, close to which its error is absolute.
+                        let reference = (x as f64).ln_gamma().0;
+                        let absolute = ((lgamma as f64) - reference).abs() <= tolerance;
+                        assert!(close(lgamma, reference, tolerance) || absolute, "lgamma({x})");
+                        x += 0.0973;
+                    }
+                    for x in [0.5, 1e-10, 1e-30, 170.5, 1e10, 1e30] {
+                        let lgamma = splat(x).lgamma()[0];
+                        let reference = (x as f64).ln_gamma().0;
+                        assert!(close(lgamma, reference, tolerance), "lgamma({x}) = {lgamma}");
+                    }
+                }
+
+                fn accuracy_modes<const LANES: usize>() {
+                    use core_simd::simd::Simd;
+                    use std_float::AccuracyMode;
+
+                    type Method<const LANES: usize> =
+                        fn(Simd<$scalar, LANES>, AccuracyMode) -> Simd<$scalar, LANES>;
+                    // Each function with its reference, and a map from `[0, 1]` to its inputs.
+                    type Inputs = fn($scalar) -> $scalar;
+                    let functions: [(Method<LANES>, fn(f64) -> f64, Inputs); 4] = [
+                        (StdFloat::exp_with, f64::exp, |t| 40.0 * t - 20.0),
+                        (StdFloat::ln_with, f64::ln, |t| 1e-6 * (1e12 as $scalar).powf(t)),
+                        (StdFloat::sin_with, f64::sin, |t| 200.0 * t - 100.0),
+                        (StdFloat::cos_with, f64::cos, |t| 200.0 * t - 100.0),
+                    ];
+                    // The error in units in the last place, against the `f64` function, which
+                    // for `f32` is far more precise.
+                    let ulps = |a: $scalar, b: f64| {
+                        let ulp = (b.abs() as $scalar).max($scalar::MIN_POSITIVE);
+                        (a as f64 - b).abs() / (ulp * $scalar::EPSILON) as f64
+                    };
+                    let fast = if $scalar::MANTISSA_DIGITS > 24 { 1024.0 } else { 64.0 };
+                    for (method, reference, inputs) in functions {
+                        for i in 0..=10000 {
+                            let x = inputs(i as $scalar / 10000.0);
+                            let b = reference(x as f64);
+                            let error = ulps(method(Simd::splat(x), AccuracyMode::Fast)[0], b);
+                            assert!(error <= fast, "Fast at {x}: {error} ulps");
+                            let error = ulps(method(Simd::splat(x), AccuracyMode::Precise)[0], b);
+                            assert!(error <= 1.0, "Precise at {x}: {error} ulps");
+                        }
+                    }
+
+                    // `Default` is the method without a mode, bit for bit.
+                    test_helpers::test_1(&|x: [$scalar; LANES]| {
+                        let v = Simd::from_array(x);
+                        let pairs = [
+                            (v.exp_with(AccuracyMode::Default), v.exp()),
+                            (v.ln_with(AccuracyMode::Default), v.ln()),
+                            (v.sin_with(AccuracyMode::Default), v.sin()),
+                            (v.cos_with(AccuracyMode::Default), v.cos()),
+                        ];
+                        for (a, b) in pairs {
+                            for i in 0..LANES {
+                                let same = a[i].to_bits() == b[i].to_bits();
+                                assert!(same || a[i].is_nan() && b[i].is_nan(), "{}", x[i]);
+                            }
+                        }
+                        Ok(())
+                    });
+                    assert_eq!(AccuracyMode::default(), AccuracyMode::Default);
+                }
+
+                fn poly<const LANES: usize>() {
+                    use core_simd::simd::Simd;
+
+                    let splat = Simd::<$scalar, LANES>::splat;
+                    let x = Simd::from_array(core::array::from_fn(|i| i as $scalar));
+                    assert_eq!(x.poly(&[]), splat(0.0));
+                    assert_eq!(x.poly(&[splat(2.5)]), splat(2.5));
+                    // `(x - 1)(x - 2)(x - 3)`, which is exact on small integers.
+                    let cubic = [splat(1.0), splat(-6.0), splat(11.0), splat(-6.0)];
+                    let expected = (x - splat(1.0)) * (x - splat(2.0)) * (x - splat(3.0));
+                    assert_eq!(x.poly(&cubic), expected);
+                    // The coefficients may differ from lane to lane.
+                    assert_eq!(splat(2.0).poly(&[x, splat(1.0)]), x * splat(2.0) + splat(1.0));
+                    let inf = splat($scalar::INFINITY);
+                    assert_eq!(inf.poly(&[splat(1.0), splat(0.0)]), inf);
+
+                    // `(1 + ε)(1 - ε) - 1 = -ε²` only with a single rounding, as the product
+                    // alone rounds to `1`.
+                    let eps = $scalar::EPSILON;
+                    let p = splat(1.0 + eps).poly(&[splat(1.0 - eps), splat(-1.0)]);
+                    assert_eq!(p, splat(-eps * eps));
+
+                    // Against a scalar chain of `mul_add`.
+                    test_helpers::test_2(&|x: [$scalar; LANES], coeffs: [$scalar; 4]| {
+                        let p = Simd::from_array(x).poly(&coeffs.map(splat));
+                        for i in 0..LANES {
+                            let q = coeffs[1..].iter().fold(coeffs[0], |q, &c| q.mul_add(x[i], c));
+                            assert!(p[i] == q || p[i].is_nan() && q.is_nan(), "{}: {}", x[i], p[i]);
+                        }
+                        Ok(())
+                    });
+                }
+
+                fn ldexp<const LANES: usize>() {
+                    use core_simd::simd::{num::SimdFloat, Simd};
+
+                    let splat = Simd::<$scalar, LANES>::splat;
+                    let n = |n: i32| Simd::<i32, LANES>::splat(n);
+                    let (max_exp, min_exp) = ($scalar::MAX_EXP, $scalar::MIN_EXP);
+                    let digits = $scalar::MANTISSA_DIGITS as i32;
+                    // The smallest subnormal, `2^(min_exp - digits)`.
+                    let tiny = $scalar::from_bits(1);
+                    assert_eq!(splat(1.5).ldexp(n(4)), splat(24.0));
+                    assert_eq!(splat(-3.0).ldexp(n(-2)), splat(-0.75));
+
+                    // Large exponents, which `2^exp` alone would overflow or underflow.
+                    assert_eq!(splat(tiny).ldexp(n(digits - min_exp)), splat(1.0));
+                    let max = splat($scalar::MAX);
+                    let largest_power = max / splat(2.0 - $scalar::EPSILON);
+                    assert_eq!(splat(tiny).ldexp(n(max_exp - min_exp + digits - 1)), largest_power);
+                    assert_eq!(max.ldexp(n(1 - max_exp)), splat(2.0 - $scalar::EPSILON));
+                    let expected = splat((2.0 - $scalar::EPSILON) * $scalar::MIN_POSITIVE);
+                    assert_eq!(max.ldexp(n(min_exp - max_exp)), expected);
+                    let inf = $scalar::INFINITY;
+                    assert_eq!(max.ldexp(n(1)), splat(inf));
+                    assert_eq!(splat(-1.0).ldexp(n(max_exp)), splat(-inf));
+                    assert_eq!(splat(1.0).ldexp(n(i32::MAX)), splat(inf));
+                    assert_eq!(splat(tiny).ldexp(n(i32::MAX)), splat(inf));
+                    assert_eq!((-max).ldexp(n(i32::MIN)).to_bits(), splat(-0.0).to_bits());
+
+                    // The subnormal boundary, where results round once, to nearest even.
+                    let min_positive = $scalar::MIN_POSITIVE;
+                    assert_eq!(splat(1.0).ldexp(n(min_exp - 1)), splat(min_positive));
+                    assert_eq!(splat(min_positive).ldexp(n(-1)), splat(min_positive / 2.0));
+                    assert_eq!(splat(1.0).ldexp(n(min_exp - 1 - digits + 1)), splat(tiny));
+                    assert_eq!(splat(tiny).ldexp(n(-1)), splat(0.0));
+                    assert_eq!(splat(3.0 * tiny).ldexp(n(-1)), splat(2.0 * tiny));
+                    assert_eq!(splat(5.0 * tiny).ldexp(n(-1)), splat(2.0 * tiny));
+                    assert_eq!(splat(1.5).ldexp(n(min_exp - digits - 1)), splat(tiny));
+                    assert_eq!(splat(tiny).ldexp(n(digits - 1)), splat(min_positive));
+                    for x in [0.0, -0.0, inf, -inf] {
+                        assert_eq!(splat(x).ldexp(n(-5000)).to_bits(), splat(x).to_bits());
+                        assert_eq!(splat(x).ldexp(n(5000)).to_bits(), splat(x).to_bits());
+                    }
+                    assert!(splat($scalar::NAN).ldexp(n(3)).is_nan().all());
+
+                    // Against multiplying by `2^exp`, which rounds once for any normal `2^exp`.
+                    test_helpers::test_2(&|x: [$scalar; LANES], e: [i32; LANES]| {
+                        let e = e.map(|e| e.rem_euclid(max_exp - min_exp + 1) + min_exp - 1);
+                        let scaled = Simd::from_array(x).ldexp(Simd::from_array(e));
+                        for i in 0..LANES {
+                            let bits = ((e[i] + max_exp - 1) as u64) << (digits - 1);
+                            let expected = x[i] * $scalar::from_bits(bits as _);
+                            let same = scaled[i] == expected
+                                || scaled[i].is_nan() && expected.is_nan();
+                            assert!(same, "{}.ldexp({}): {} != {expected}", x[i], e[i], scaled[i]);
+                        }
+                        Ok(())
+                    });
+                }
+
+                fn copysign_bits<const LANES: usize>() {
+                    use core_simd::simd::{num::SimdFloat, Simd};
+
+                    let splat = Simd::<$scalar, LANES>::splat;
+                    let copysign = |x: $scalar, sign: $scalar| splat(x).copysign(splat(sign))[0];
+                    assert_eq!(copysign(3.0, -0.0), -3.0);
+                    assert_eq!(copysign(-3.0, 0.0), 3.0);
+                    assert_eq!(copysign(0.0, -1.0).to_bits(), (-0.0 as $scalar).to_bits());
+                    assert_eq!(copysign(-0.0, 1.0).to_bits(), (0.0 as $scalar).to_bits());
+                    let inf = $scalar::INFINITY;
+                    assert_eq!(copysign(inf, -2.0), -inf);
+                    assert_eq!(copysign(-inf, inf), inf);
+                    assert_eq!(copysign(2.0, -inf), -2.0);
+
+                    // The sign of `NaN` is copied, and the payload of `NaN` is kept.
+                    let nan = $scalar::from_bits($scalar::NAN.to_bits() | 1);
+                    assert_eq!(copysign(2.0, -nan), -2.0);
+                    assert_eq!(copysign(2.0, nan), 2.0);
+                    assert_eq!(copysign(nan, -1.0).to_bits(), (-nan).to_bits());
+                    assert_eq!(copysign(-nan, 1.0).to_bits(), nan.to_bits());
+                }
+
+                fn signum_special_values<const LANES: usize>() {
+                    use core_simd::simd::{num::SimdFloat, Simd};
+
+                    let splat = Simd::<$scalar, LANES>::splat;
+                    let inf = $scalar::INFINITY;
+                    for (x, signum) in [
+                        (0.0, 1.0),
+                        (-0.0, -1.0),
+                        ($scalar::MIN_POSITIVE / 2.0, 1.0),
+                        (-$scalar::MIN_POSITIVE / 2.0, -1.0),
+                        (2.5, 1.0),
+                        (-2.5, -1.0),
+                        (inf, 1.0),
+                        (-inf, -1.0),
+                    ] {
+                        assert_eq!(splat(x).signum(), splat(signum), "{x}");
+                    }
+                    assert!(splat($scalar::NAN).signum().is_nan().all());
+                    assert!(splat(-$scalar::NAN).signum().is_nan().all());
+                }
+
+                fn hypot<const LANES: usize>() {
+                    use core_simd::simd::Simd;
+
+                    let splat = Simd::<$scalar, LANES>::splat;
+                    let hypot = |x: $scalar, y: $scalar| splat(x).hypot(splat(y))[0];
+                    assert_eq!(hypot(3.0, 4.0), 5.0);
+                    assert_eq!(hypot(-3.0, 4.0), 5.0);
+                    assert_eq!(hypot(0.0, 0.0).to_bits(), (0.0 as $scalar).to_bits());
+                    assert_eq!(hypot(-0.0, -0.0).to_bits(), (0.0 as $scalar).to_bits());
+                    assert_eq!(hypot(0.0, -2.0), 2.0);
+
+                    // Squaring either leg would overflow or underflow.
+                    let big = $scalar::MAX / 4.0;
+                    assert_eq!(hypot(big / 4.0 * 3.0, big), big / 4.0 * 5.0);
+                    assert!(hypot($scalar::MAX, 1.0).is_finite());
+                    assert_eq!(hypot($scalar::MAX, $scalar::MAX), $scalar::INFINITY);
+                    let small = $scalar::MIN_POSITIVE;
+                    assert_eq!(hypot(small * 3.0, small * 4.0), small * 5.0);
+                    let tiny = $scalar::from_bits(1);
+                    assert_eq!(hypot(tiny * 3.0, tiny * 4.0), tiny * 5.0);
+
+                    let inf = $scalar::INFINITY;
+                    assert!(hypot($scalar::NAN, 1.0).is_nan());
+                    assert!(hypot(1.0, $scalar::NAN).is_nan());
+                    assert_eq!(hypot(inf, $scalar::NAN), inf);
+                    assert_eq!(hypot($scalar::NAN, -inf), inf);
+
+                    // `NaN`s and zeros only affect their own lanes.
+                    let mut x = splat(3.0);
+                    let mut y = splat(4.0);
+                    x[0] = $scalar::NAN;
+                    if LANES > 1 {
+                        (x[1], y[1]) = (0.0, 0.0);
+                    }
+                    let h = x.hypot(y);
+                    assert!(h[0].is_nan());
+                    if LANES > 1 {
+                        assert_eq!(h[1], 0.0);
+                    }
+                    for i in 2..LANES {
+                        assert_eq!(h[i], 5.0);
+                    }
+
+                    // Within two units in the last place of the scalar function.
+                    test_helpers::test_2(&|x: [$scalar; LANES], y: [$scalar; LANES]| {
+                        let h = Simd::from_array(x).hypot(Simd::from_array(y));
+                        for i in 0..LANES {
+                            let (a, b) = (h[i], x[i].hypot(y[i]));
+                            let ulp = b.max($scalar::MIN_POSITIVE) * $scalar::EPSILON;
+                            let close = a == b || (a - b).abs() <= 2.0 * ulp;
+                            let close = close || a.is_nan() && b.is_nan();
+                            assert!(close, "hypot({}, {}): {a} != {b}", x[i], y[i]);
+                        }
+                        Ok(())
+                    });
+                }
+
+                fn cbrt<const LANES: usize>() {
+                    use core_simd::simd::{num::SimdFloat, Simd};
+
+                    let splat = Simd::<$scalar, LANES>::splat;
+                    assert_eq!(splat(8.0).cbrt(), splat(2.0));
+                    assert_eq!(splat(-8.0).cbrt(), splat(-2.0));
+                    assert_eq!(splat(-27.0).cbrt(), splat(-3.0));
+                    assert_eq!(splat(1.0).cbrt(), splat(1.0));
+                    assert_eq!(splat(0.0).cbrt().to_bits(), splat(0.0).to_bits());
+                    assert_eq!(splat(-0.0).cbrt().to_bits(), splat(-0.0).to_bits());
+                    let inf = $scalar::INFINITY;
+                    assert_eq!(splat(inf).cbrt(), splat(inf));
+                    assert_eq!(splat(-inf).cbrt(), splat(-inf));
+                    assert!(splat($scalar::NAN).cbrt().is_nan().all());
+
+                    // Within one unit in the last place of the exact root, computed in `f64`.
+                    let check = |x: $scalar| {
+                        let root = splat(x).cbrt()[0];
+                        let exact = (x as f64).cbrt();
+                        let ulp = (exact.abs() as $scalar).max($scalar::MIN_POSITIVE) as f64
+                            * $scalar::EPSILON as f64;
+                        assert!(((root as f64) - exact).abs() <= ulp, "{x}: {root} != {exact}");
+                    };
+                    for x in [
+                        2.0, 3.0, 0.1, 1e-3, 1e30, $scalar::MAX, $scalar::MIN_POSITIVE,
+                        $scalar::MIN_POSITIVE / 3.0, $scalar::from_bits(1),
+                    ] {
+                        check(x);
+                        check(-x);
+                    }
+                    test_helpers::test_1(&|x: [$scalar; LANES]| {
+                        for x in x {
+                            if x.is_finite() {
+                                check(x);
+                            }
+                        }
+                        Ok(())
+                    });
+                }
+
+                fn inverse_trigonometric_domains<const LANES: usize>() {
+                    use core::$scalar::consts::{FRAC_PI_2, PI};
+                    use core_simd::simd::{num::SimdFloat, Simd};
+
+                    let splat = Simd::<$scalar, LANES>::splat;
+                    let check = |v: Simd<$scalar, LANES>, expected: $scalar| {
+                        assert!(v.to_array().iter().all(|&a| matches_scalar(a, expected)));
+                    };
+                    check(splat(1.0).asin(), FRAC_PI_2);
+                    check(splat(-1.0).asin(), -FRAC_PI_2);
+                    assert_eq!(splat(0.0).asin().to_bits(), splat(0.0).to_bits());
+                    assert_eq!(splat(-0.0).asin().to_bits(), splat(-0.0).to_bits());
+                    assert_eq!(splat(1.0).acos().to_bits(), splat(0.0).to_bits());
+                    check(splat(-1.0).acos(), PI);
+                    check(splat(0.0).acos(), FRAC_PI_2);
+                    let eps = $scalar::EPSILON;
+                    let outside = [1.0 + eps, -1.0 - eps, 2.0, $scalar::INFINITY];
+                    for x in outside {
+                        assert!(splat(x).asin().is_nan().all());
+                        assert!(splat(x).acos().is_nan().all());
+                    }
+
+                    // Out-of-domain lanes don't affect the others.
+                    let mut v = splat(0.5);
+                    v[0] = 3.0;
+                    let (asin, acos) = (v.asin(), v.acos());
+                    assert!(asin[0].is_nan() && acos[0].is_nan());
+                    for i in 1..LANES {
+                        assert!(matches_scalar(asin[i], (0.5 as $scalar).asin()));
+                        assert!(matches_scalar(acos[i], (0.5 as $scalar).acos()));
+                    }
+                }
+
+                fn atan2_quadrants<const LANES: usize>() {
+                    use core_simd::simd::Simd;
+
+                    // A grid over every quadrant, the axes, signed zeros and infinities. The
+                    // result must have the same bits as the scalar `atan2`.
+                    let values = [
+                        0.0, -0.0, 0.5, -0.5, 1.0, -1.0, 3.0, -3.0, 1e30, -1e30,
+                        $scalar::INFINITY, $scalar::NEG_INFINITY,
+                    ];
+                    for y in values {
+                        for x in values {
+                            let v = Simd::<$scalar, LANES>::splat(y).atan2(Simd::splat(x));
+                            let expected = y.atan2(x);
+                            for lane in v.to_array() {
+                                assert!(matches_scalar(lane, expected), "atan2({y}, {x})");
+                            }
+                        }
+                    }
+                    let nan = Simd::<$scalar, LANES>::splat($scalar::NAN);
+                    assert!(nan.atan2(Simd::splat(1.0))[0].is_nan());
+                    assert!(Simd::<$scalar, LANES>::splat(1.0).atan2(nan)[0].is_nan());
+                }
+
+                fn inverse_hyperbolic_domains<const LANES: usize>() {
+                    use core_simd::simd::{num::SimdFloat, Simd};
+
+                    let splat = Simd::<$scalar, LANES>::splat;
+                    assert_eq!(splat(1.0).acosh().to_bits(), splat(0.0).to_bits());
+                    assert!(splat(1.0 - $scalar::EPSILON).acosh().is_nan().all());
+                    assert!(splat(-1.0).acosh().is_nan().all());
+                    assert_eq!(splat(1.0).atanh(), splat($scalar::INFINITY));
+                    assert_eq!(splat(-1.0).atanh(), splat($scalar::NEG_INFINITY));
+                    assert!(splat(1.0 + $scalar::EPSILON).atanh().is_nan().all());
+                    assert!(splat(-2.0).atanh().is_nan().all());
+                    assert_eq!(splat(-0.0).asinh().to_bits(), splat(-0.0).to_bits());
+                    assert_eq!(splat(-0.0).atanh().to_bits(), splat(-0.0).to_bits());
+                    assert_eq!(splat($scalar::INFINITY).asinh(), splat($scalar::INFINITY));
+                    assert_eq!(splat($scalar::NEG_INFINITY).asinh(), splat($scalar::NEG_INFINITY));
+                    assert_eq!(splat($scalar::INFINITY).acosh(), splat($scalar::INFINITY));
+                }
+
+                fn inverse_hyperbolic_accuracy<const LANES: usize>() {
+                    use core_simd::simd::Simd;
+
+                    // Within a few units in the last place of the scalar functions, over a range
+                    // of magnitudes and close to the domain boundaries.
+                    let check = |f: fn(Simd<$scalar, LANES>) -> Simd<$scalar, LANES>,
+                                 g: fn($scalar) -> $scalar,
+                                 inputs: &[$scalar]| {
+                        for &x in inputs {
+                            let (a, b) = (f(Simd::splat(x))[0], g(x));
+                            let ulp = b.abs().max($scalar::MIN_POSITIVE) * $scalar::EPSILON;
+                            assert!(a == b || (a - b).abs() <= 4.0 * ulp, "{x}: {a} != {b}");
+                        }
+                    };
+                    let mut magnitudes = vec![];
+                    for exp in $scalar::MIN_EXP..$scalar::MAX_EXP {
+                        for m in [1.0, 1.1, 1.5, 1.9] {
+                            magnitudes.push(m * (2.0 as $scalar).powi(exp));
+                        }
+                    }
+                    let signed: Vec<_> = magnitudes.iter().flat_map(|&x| [x, -x]).collect();
+                    check(StdFloat::asinh, $scalar::asinh, &signed);
+
+                    let mut above_one: Vec<_> = magnitudes.iter().map(|&x| 1.0 + x).collect();
+                    above_one.extend([1.0, 1.0 + $scalar::EPSILON, 2.0 - $scalar::EPSILON, 2.0]);
+                    check(StdFloat::acosh, $scalar::acosh, &above_one);
+
+                    let mut inside: Vec<_> =
+                        signed.iter().copied().filter(|x| x.abs() < 1.0).collect();
+                    for k in 1..$scalar::MANTISSA_DIGITS {
+                        let near_one = 1.0 - (2.0 as $scalar).powi(-(k as i32));
+                        inside.extend([near_one, -near_one]);
+                    }
+                    // The scalar `atanh` loses accuracy for negative arguments close to `-1`,
+                    // so compare with the result for the magnitude instead.
+                    check(StdFloat::atanh, |x| x.abs().atanh().copysign(x), &inside);
+                }
+
+                fn approx_improves_with_degree<const LANES: usize>() {
+                    use core_simd::simd::Simd;
+
+                    // The largest error over the inputs, in units of the result's epsilon.
+                    let max_error = |approx: &dyn Fn(Simd<$scalar, LANES>) -> Simd<$scalar, LANES>,
+                                     exact: fn($scalar) -> $scalar,
+                                     inputs: &[$scalar]| {
+                        let mut max: $scalar = 0.0;
+                        for &x in inputs {
+                            let (a, b) = (approx(Simd::splat(x))[0], exact(x));
+                            let ulp = b.abs().max($scalar::MIN_POSITIVE) * $scalar::EPSILON;
+                            max = max.max((a - b).abs() / ulp);
+                        }
+                        max
+                    };
+                    let check = |errors: &[$scalar]| {
+                        for pair in errors.windows(2) {
+                            assert!(pair[1] <= pair[0].max(2.0), "{errors:?}");
+                        }
+                        assert!(*errors.last().unwrap() <= 2.0, "{errors:?}");
+                    };
+
+                    let inputs: Vec<$scalar> = (-800..=800).map(|i| i as $scalar / 10.0).collect();
+                    let errors: Vec<_> = (1..=11)
+                        .map(|d| max_error(&|x| x.exp_approx(d), $scalar::exp, &inputs))
+                        .collect();
+                    check(&errors);
+
+                    let inputs: Vec<$scalar> =
+                        (-1000..=1000).map(|i| i as $scalar / 10.0).collect();
+                    let errors: Vec<_> = (3..=17)
+                        .step_by(2)
+                        .map(|d| max_error(&|x| x.sin_approx(d), $scalar::sin, &inputs))
+                        .collect();
+                    check(&errors);
+
+                    let mut inputs: Vec<$scalar> =
+                        (-800..=800).map(|i| (i as $scalar / 10.0).exp()).collect();
+                    inputs.push($scalar::MIN_POSITIVE / 4.0);
+                    let errors: Vec<_> = (3..=15)
+                        .step_by(2)
+                        .map(|d| max_error(&|x| x.ln_approx(d), $scalar::ln, &inputs))
+                        .collect();
+                    check(&errors);
+                }
+
+                fn exp_m1_ln_1p<const LANES: usize>() {
+                    use core_simd::simd::Simd;
+
+                    // Within a few units in the last place of the scalar functions, for tiny
+                    // elements where subtracting or adding one directly loses all precision, and
+                    // on either side of the switch to the direct formulas.
+                    let check = |f: fn(Simd<$scalar, LANES>) -> Simd<$scalar, LANES>,
+                                 g: fn($scalar) -> $scalar,
+                                 inputs: &[$scalar]| {
+                        for &x in inputs {
+                            let (a, b) = (f(Simd::splat(x))[0], g(x));
+                            let ulp = b.abs().max($scalar::MIN_POSITIVE) * $scalar::EPSILON;
+                            assert!(a == b || (a - b).abs() <= 2.0 * ulp, "{x}: {a} != {b}");
+                        }
+                    };
+                    let mut inputs = vec![1e-10, -1e-10, 1e-30, -1e-30, 1e-5, -1e-5];
+                    let mut x: $scalar = -0.9;
+                    while x < 10.0 {
+                        inputs.push(x);
+                        x += 0.0001;
+                    }
+                    check(StdFloat::exp_m1, $scalar::exp_m1, &inputs);
+                    check(StdFloat::ln_1p, $scalar::ln_1p, &inputs);
+
+                    // The relative error at tiny elements, against the `f64` functions.
+                    let splat = Simd::<$scalar, LANES>::splat;
+                    for x in [1e-10, -1e-10, 3e-8, -3e-8] {
+                        let exp_m1 = splat(x).exp_m1()[0] as f64;
+                        let ln_1p = splat(x).ln_1p()[0] as f64;
+                        let (x, epsilon) = (x as f64, $scalar::EPSILON as f64);
+                        assert!((exp_m1 / x.exp_m1() - 1.0).abs() <= epsilon, "{x}: {exp_m1}");
+                        assert!((ln_1p / x.ln_1p() - 1.0).abs() <= epsilon, "{x}: {ln_1p}");
+                    }
+                }
+
+                fn sin_cos<const LANES: usize>() {
+                    use core_simd::simd::{num::SimdFloat, Simd};
+
+                    // Within a unit in the last place of `sin` and `cos`, lane for lane, both for
+                    // elements reduced together and for large ones that fall back to them.
+                    let check = |x: [$scalar; LANES]| {
+                        let v = Simd::from_array(x);
+                        let (sin, cos) = v.sin_cos();
+                        let (sin_b, cos_b) = (v.sin(), v.cos());
+                        for i in 0..LANES {
+                            for (a, b) in [(sin[i], sin_b[i]), (cos[i], cos_b[i])] {
+                                let ulp = b.abs().max($scalar::MIN_POSITIVE) * $scalar::EPSILON;
+                                let close = a == b || (a - b).abs() <= ulp;
+                                let close = close || a.is_nan() && b.is_nan();
+                                assert!(close, "sin_cos({}): {a} != {b}", x[i]);
+                            }
+                        }
+                    };
+                    let mut x: $scalar = -100.0;
+                    while x < 100.0 {
+                        check(core::array::from_fn(|i| x + i as $scalar * 0.001));
+                        x += 0.01;
+                    }
+                    let special = [0.0, -0.0, 1e-30, $scalar::MAX, $scalar::INFINITY, $scalar::NAN];
+                    for x in special {
+                        check([x; LANES]);
+                        check([-x; LANES]);
+                    }
+                    let zero = Simd::<$scalar, LANES>::splat(-0.0);
+                    assert_eq!(zero.sin_cos().0.to_bits(), zero.to_bits());
+                    test_helpers::test_1(&|x: [$scalar; LANES]| {
+                        check(x);
+                        Ok(())
+                    });
+                }
+
+                fn hyperbolic<const LANES: usize>() {
+                    use core_simd::simd::{num::SimdFloat, Simd};
+
+                    let splat = Simd::<$scalar, LANES>::splat;
+                    for x in [0.0, -0.0] {
+                        assert_eq!(splat(x).sinh().to_bits(), splat(x).to_bits());
+                        assert_eq!(splat(x).cosh(), splat(1.0));
+                        assert_eq!(splat(x).tanh().to_bits(), splat(x).to_bits());
+                    }
+
+                    // Large elements overflow or saturate instead of producing `NaN`.
+                    let inf = $scalar::INFINITY;
+                    for x in [1000.0, $scalar::MAX, inf] {
+                        assert_eq!(splat(x).sinh(), splat(inf));
+                        assert_eq!(splat(-x).sinh(), splat(-inf));
+                        assert_eq!(splat(x).cosh(), splat(inf));
+                        assert_eq!(splat(-x).cosh(), splat(inf));
+                        assert_eq!(splat(x).tanh(), splat(1.0));
+                        assert_eq!(splat(-x).tanh(), splat(-1.0));
+                    }
+                    // Just below the overflow threshold, `exp` overflows but `cosh` doesn't.
+                    let x = $scalar::MAX.ln() + 0.5;
+                    assert!(splat(x).cosh().is_finite().all());
+                    assert!(splat(x).sinh().is_finite().all());
+                    for f in [StdFloat::sinh, StdFloat::cosh, StdFloat::tanh] {
+                        assert!(f(splat($scalar::NAN)).is_nan().all());
+                    }
+
+                    // Within a few units in the last place of the scalar functions.
+                    let check = |f: fn(Simd<$scalar, LANES>) -> Simd<$scalar, LANES>,
+                                 g: fn($scalar) -> $scalar| {
+                        let mut x: $scalar = -90.0;
+                        while x <= 90.0 {
+                            for x in [x, x * 1e-6] {
+                                let (a, b) = (f(splat(x))[0], g(x));
+                                let ulp = b.abs().max($scalar::MIN_POSITIVE) * $scalar::EPSILON;
+                                assert!(a == b || (a - b).abs() <= 4.0 * ulp, "{x}: {a} != {b}");
+                            }
+                            x += 0.01;
+                        }
+                    };
+                    check(StdFloat::sinh, $scalar::sinh);
+                    check(StdFloat::cosh, $scalar::cosh);
+                    check(StdFloat::tanh, $scalar::tanh);
+                }
+
+                fn approx_special_values<const LANES: usize>() {
+                    use core_simd::simd::{num::SimdFloat, Simd};
+
+                    let splat = Simd::<$scalar, LANES>::splat;
+                    let inf = splat($scalar::INFINITY);
+                    for degree in [0, 1, 5, 11, 100] {
+                        assert_eq!(inf.exp_approx(degree), inf);
+                        assert_eq!(splat(1000.0).exp_approx(degree), inf);
+                        assert_eq!((-inf).exp_approx(degree), splat(0.0));
+                        assert_eq!(splat(-1000.0).exp_approx(degree), splat(0.0));
+                        assert!(splat($scalar::NAN).exp_approx(degree).is_nan().all());
+
+                        assert!(inf.sin_approx(degree).is_nan().all());
+                        assert!(splat($scalar::NAN).sin_approx(degree).is_nan().all());
+
+                        assert_eq!(splat(0.0).ln_approx(degree), -inf);
+                        assert_eq!(inf.ln_approx(degree), inf);
+                        assert!(splat(-1.0).ln_approx(degree).is_nan().all());
+                        assert!(splat($scalar::NAN).ln_approx(degree).is_nan().all());
+                    }
+                }
+
+                fn recip_approx<const LANES: usize>() {
+                    use core_simd::simd::{num::SimdFloat, Simd};
+
+                    // Within 3 units in the last place of `SimdFloat::recip`, which is correctly
+                    // rounded, for elements whose reciprocal is normal.
+                    test_helpers::test_1(&|x: [$scalar; LANES]| {
+                        let v = Simd::from_array(x);
+                        let (exact, approx) = (v.recip(), v.recip_approx());
+                        for i in 0..LANES {
+                            let expected = exact[i];
+                            if expected.is_normal() && x[i].is_normal() {
+                                let error = (approx[i] - expected).abs();
+                                let ulp = expected.abs() * $scalar::EPSILON;
+                                assert!(
+                                    error <= 3.0 * ulp,
+                                    "{}.recip_approx(): {} != {expected}",
+                                    x[i],
+                                    approx[i],
+                                );
+                            }
+                        }
+                        Ok(())
+                    });
+
+                    // A sweep over the mantissas of one binade, where the estimates are worst.
+                    let splat = Simd::<$scalar, LANES>::splat;
+                    for i in 0..1 << 16 {
+                        let x = 1.0 + i as $scalar / (1 << 16) as $scalar;
+                        let (a, b) = (splat(x).recip_approx()[0], splat(x).recip()[0]);
+                        assert!((a - b).abs() <= 3.0 * b * $scalar::EPSILON, "{x}: {a} != {b}");
+                    }
+
+                    let inf = $scalar::INFINITY;
+                    for (x, expected) in [
+                        (0.0, inf),
+                        (-0.0, -inf),
+                        ($scalar::MIN_POSITIVE / 2.0, inf),
+                        (inf, 0.0),
+                        (-inf, -0.0),
+                        ($scalar::MAX, 0.0),
+                        (-$scalar::MAX, -0.0),
+                    ] {
+                        let approx = splat(x).recip_approx();
+                        assert_eq!(approx.to_bits(), splat(expected).to_bits(), "{x}");
+                    }
+                    assert!(splat($scalar::NAN).recip_approx().is_nan().all());
+                }
+
+                fn tanh_approx<const LANES: usize>() {
+                    use core_simd::simd::{num::SimdFloat, Simd};
+
+                    let splat = Simd::<$scalar, LANES>::splat;
+                    let mut x: $scalar = -20.0;
+                    while x <= 20.0 {
+                        let (a, b) = (splat(x).tanh_approx(), splat(-x).tanh_approx());
+                        assert!((a[0] - x.tanh()).abs() <= 1e-4, "{x}: {} != {}", a[0], x.tanh());
+                        assert_eq!(a, -b, "{x}");
+                        x += 0.001;
+                    }
+
+                    // Large elements saturate.
+                    for x in [5.0, 10.0, 1e10, $scalar::MAX, $scalar::INFINITY] {
+                        assert_eq!(splat(x).tanh_approx(), splat(1.0), "{x}");
+                        assert_eq!(splat(-x).tanh_approx(), splat(-1.0), "{x}");
+                    }
+                    assert_eq!(splat(0.0).tanh_approx().to_bits(), splat(0.0).to_bits());
+                    assert_eq!(splat(-0.0).tanh_approx().to_bits(), splat(-0.0).to_bits());
+                    assert!(splat($scalar::NAN).tanh_approx().is_nan().all());
+                }
+
+                fn powf<const LANES: usize>() {
+                    use core_simd::simd::Simd;
+
+                    let (inf, nan) = ($scalar::INFINITY, $scalar::NAN);
+                    #[rustfmt::skip]
+                    let cases: &[($scalar, $scalar, $scalar)] = &[
+                        // Exponents of zero and bases of one.
+                        (0.0, 0.0, 1.0), (-0.0, -0.0, 1.0), (-2.0, 0.0, 1.0), (inf, 0.0, 1.0),
+                        (-inf, -0.0, 1.0), (nan, 0.0, 1.0), (1.0, 2.5, 1.0), (1.0, -3.0, 1.0),
+                        (1.0, inf, 1.0), (1.0, -inf, 1.0), (1.0, nan, 1.0), (-1.0, inf, 1.0),
+                        (-1.0, -inf, 1.0),
+                        // Negative bases.
+                        (-1.0, 3.0, -1.0), (-1.0, 2.0, 1.0), (-1.0, -1.0, -1.0),
+                        (-2.0, 0.5, nan), (-2.0, -0.5, nan), (-2.0, 1.0 / 3.0, nan),
+                        (-2.0, 2.25, nan),
+                        // Zeros and infinities.
+                        (0.0, 3.0, 0.0), (-0.0, 3.0, -0.0), (-0.0, 2.0, 0.0), (-0.0, 0.5, 0.0),
+                        (0.0, -1.0, inf), (-0.0, -1.0, -inf), (-0.0, -2.0, inf), (-0.0, -0.5, inf),
+                        (inf, 2.0, inf), (inf, -2.0, 0.0), (-inf, 3.0, -inf), (-inf, 2.0, inf),
+                        (-inf, 0.5, inf), (-inf, -3.0, -0.0), (-inf, -0.5, 0.0), (0.5, inf, 0.0),
+                        (2.0, inf, inf), (-2.0, inf, inf), (0.5, -inf, inf), (-2.0, -inf, 0.0),
+                        // NaNs.
+                        (nan, 1.0, nan), (2.0, nan, nan), (-2.0, nan, nan),
+                    ];
+
+                    // Each case is checked in every lane, next to the other cases.
+                    for offset in 0..cases.len() {
+                        let case = |i: usize| cases[(offset + i) % cases.len()];
+                        let x = Simd::from_array(core::array::from_fn(|i| case(i).0));
+                        let n = Simd::from_array(core::array::from_fn(|i| case(i).1));
+                        let p: Simd<$scalar, LANES> = x.powf(n);
+                        for i in 0..LANES {
+                            let (x, n, expected) = case(i);
+                            let same = p[i].to_bits() == expected.to_bits();
+                            assert!(
+                                same || p[i].is_nan() && expected.is_nan(),
+                                "{x}.powf({n}): {} != {expected}",
+                                p[i],
+                            );
+                        }
+                    }
+
+                    // A negative base raised to an integer power has the magnitude of the
+                    // positive base, and the sign of the exponent's parity.
+                    for n in -5..=5 {
+                        let n = Simd::<$scalar, LANES>::splat(n as $scalar);
+                        let x = Simd::from_array(core::array::from_fn(|i| i as $scalar + 1.5));
+                        let sign = if n[0] % 2.0 == 0.0 { 1.0 } else { -1.0 };
+                        assert_eq!((-x).powf(n), x.powf(n) * Simd::splat(sign));
+                    }
+
+                    // Elsewhere, within the stated error of the scalar function.
+                    test_helpers::test_2(&|x: [$scalar; LANES], n: [$scalar; LANES]| {
+                        let p = Simd::from_array(x).powf(Simd::from_array(n));
+                        for i in 0..LANES {
+                            let (a, b) = (p[i], x[i].powf(n[i]));
+                            if a == b || a.is_nan() || b.is_nan() || b.is_infinite() || b == 0.0 {
+                                assert!(a.is_nan() == b.is_nan(), "{}.powf({})", x[i], n[i]);
+                                continue;
+                            }
+                            let ulp = b.abs().max($scalar::MIN_POSITIVE) * $scalar::EPSILON;
+                            let error = (a - b).abs() / ulp;
+                            let bound = 2.0 + (n[i] * x[i].abs().ln()).abs();
+                            assert!(error <= bound, "{}.powf({}): {a} != {b}", x[i], n[i]);
+                        }
+                        Ok(())
+                    });
+                }
+
+                fn powi<const LANES: usize>() {
+                    use core_simd::simd::Simd;
+
+                    // Every lane gets its own exponent, from -24 up to 24.
+                    let n = Simd::<i32, LANES>::from_array(core::array::from_fn(|i| {
+                        (i as i32 * 7) % 49 - 24
+                    }));
+                    test_helpers::test_1(&|x: [$scalar; LANES]| {
+                        let p = Simd::from_array(x).powi(n);
+                        for i in 0..LANES {
+                            let expected = (x[i] as f64).powi(n[i]) as $scalar;
+                            let ulp = expected.abs().max($scalar::MIN_POSITIVE) * $scalar::EPSILON;
+                            let bound = (n[i].unsigned_abs() + 1) as $scalar * ulp;
+                            assert!(
+                                p[i] == expected
+                                    || (p[i] - expected).abs() <= bound
+                                    || p[i].is_nan() && expected.is_nan()
+                                    // The positive power overflows before it is reciprocated.
+                                    || n[i] < 0 && p[i] == 0.0 && expected.abs() < 1.0,
+                                "{}.powi({}): {} != {expected}",
+                                x[i],
+                                n[i],
+                                p[i],
+                            );
+                        }
+                        Ok(())
+                    });
+
+                    let splat = Simd::<$scalar, LANES>::splat;
+                    let n = |n: i32| Simd::<i32, LANES>::splat(n);
+                    assert_eq!(splat(2.0).powi(n(10)), splat(1024.0));
+                    assert_eq!(splat(-2.0).powi(n(3)), splat(-8.0));
+                    assert_eq!(splat(-2.0).powi(n(-3)), splat(-0.125));
+                    assert_eq!(splat(3.0).powi(n(1)), splat(3.0));
+                    assert_eq!(splat(0.0).powi(n(-1)), splat($scalar::INFINITY));
+                    assert_eq!(splat(2.0).powi(n(i32::MIN)), splat(0.0));
+                    assert_eq!(splat(1.0).powi(n(i32::MAX)), splat(1.0));
+                    for x in [0.0, -0.0, 2.5, $scalar::INFINITY, $scalar::NAN] {
+                        assert_eq!(splat(x).powi(n(0)), splat(1.0), "{x}");
+                    }
+                }
+
+                fn powf_real<const LANES: usize>() {
+                    use core_simd::simd::{cmp::SimdPartialOrd, num::SimdFloat, Simd};
+
+                    let splat = Simd::<$scalar, LANES>::splat;
+                    let close = |a: Simd<$scalar, LANES>, b: $scalar| {
+                        let tolerance = splat(4.0 * $scalar::EPSILON * b.abs());
+                        (a - splat(b)).abs().simd_le(tolerance).all()
+                    };
+                    assert!(close(splat(-8.0).powf_real(splat(1.0 / 3.0)), -2.0));
+                    assert!(close(splat(-27.0).powf_real(splat(1.0 / 3.0)), -3.0));
+                    assert!(close(splat(-32.0).powf_real(splat(1.0 / 5.0)), -2.0));
+                    assert!(close(splat(-8.0).powf_real(splat(-1.0 / 3.0)), -0.5));
+                    assert_eq!(
+                        splat($scalar::NEG_INFINITY).powf_real(splat(1.0 / 3.0)),
+                        splat($scalar::NEG_INFINITY)
+                    );
+                    assert_eq!(splat(-2.0).powf_real(splat(3.0)), splat(-8.0));
+                    assert_eq!(splat(-2.0).powf_real(splat(1.0)), splat(-2.0));
+                    assert_eq!(splat(-2.0).powf_real(splat(0.0)), splat(1.0));
+
+                    // Exponents that are not the reciprocal of an odd integer have no real result.
+                    for exp in [0.5, 0.25, 0.4, 2.0 / 3.0, 1.5] {
+                        assert!(splat(-8.0).powf_real(splat(exp)).is_nan().all(), "{exp}");
+                    }
+
+                    test_helpers::test_2(&|x: [$scalar; LANES], exp: [$scalar; LANES]| {
+                        let x = Simd::from_array(x).abs();
+                        let exp = Simd::from_array(exp);
+                        let pow = x.powf_real(exp);
+                        for i in 0..LANES {
+                            let expected = x[i].powf(exp[i]);
+                            assert!(matches_scalar(pow[i], expected), "{}, {}", x[i], exp[i]);
+                        }
+                        Ok(())
+                    });
+                }
+
+                fn catmull_rom<const LANES: usize>() {
+                    use core_simd::simd::Simd;
+
+                    test_helpers::test_2(&|p: [$scalar; 4], t: $scalar| {
+                        // Keep the points and parameter in a range where the cubic is well
+                        // conditioned, so that a scalar reference can be compared to.
+                        let p = p.map(|x| x % 1000.0);
+                        let t = (t % 1.0).abs();
+                        if p.iter().any(|x| x.is_nan()) || t.is_nan() {
+                            return Ok(());
+                        }
+                        let [p0, p1, p2, p3] = p.map(Simd::<$scalar, LANES>::splat);
+                        let scale = p.iter().fold(1.0, |a: $scalar, b| a.max(b.abs()));
+                        let tolerance = 64.0 * $scalar::EPSILON * scale;
+
+                        let at_start = Simd::catmull_rom(p0, p1, p2, p3, Simd::splat(0.0));
+                        assert_eq!(at_start, p1);
+
+                        let at_end = Simd::catmull_rom(p0, p1, p2, p3, Simd::splat(1.0));
+                        for x in at_end.to_array() {
+                            assert!((x - p[2]).abs() <= tolerance, "{x} != {}", p[2]);
+                        }
+
+                        let [p0, p1, p2, p3] = p.map(|x| x as f64);
+                        let t64 = t as f64;
+                        let expected = 0.5
+                            * (2.0 * p1
+                                + (p2 - p0) * t64
+                                + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t64 * t64
+                                + (3.0 * (p1 - p2) + p3 - p0) * t64 * t64 * t64);
+                        let [p0, p1, p2, p3] = p.map(Simd::<$scalar, LANES>::splat);
+                        let value = Simd::catmull_rom(p0, p1, p2, p3, Simd::splat(t));
+                        for x in value.to_array() {
+                            let x = x as f64;
+                            assert!((x - expected).abs() <= tolerance as f64, "{x} != {expected}");
+                        }
+                        Ok(())
+                    });
+                }
+
+                fn bilerp<const LANES: usize>() {
+                    use core_simd::simd::Simd;
+
+                    test_helpers::test_1(&|c: [$scalar; 4]| {
+                        let c = c.map(|x| x % 1000.0);
+                        if c.iter().any(|x| x.is_nan()) {
+                            return Ok(());
+                        }
+                        let [c00, c10, c01, c11] = c.map(Simd::<$scalar, LANES>::splat);
+                        let at = |tx: $scalar, ty: $scalar| {
+                            Simd::bilerp(c00, c10, c01, c11, Simd::splat(tx), Simd::splat(ty))
+                        };
+                        assert_eq!(at(0.0, 0.0), c00);
+                        assert_eq!(at(1.0, 0.0), c10);
+                        assert_eq!(at(0.0, 1.0), c01);
+                        assert_eq!(at(1.0, 1.0), c11);
+
+                        let average = (c[0] + c[1] + c[2] + c[3]) / 4.0;
+                        let scale = c.iter().fold(1.0, |a: $scalar, b| a.max(b.abs()));
+                        let tolerance = 8.0 * $scalar::EPSILON * scale;
+                        for x in at(0.5, 0.5).to_array() {
+                            assert!((x - average).abs() <= tolerance, "{x} != {average}");
+                        }
+                        Ok(())
+                    });
+                }
+
+                fn angle_diff<const LANES: usize>() {
+                    use core::$scalar::consts::{PI, TAU};
+                    use core_simd::simd::{num::SimdFloat, Simd};
+
+                    let splat = Simd::<$scalar, LANES>::splat;
+                    // Allow for rounding in the computation of the expected values.
+                    let close = |a: Simd<$scalar, LANES>, b: $scalar| {
+                        let tolerance = 8.0 * $scalar::EPSILON * (1.0 + b.abs());
+                        (a - splat(b)).abs().to_array().iter().all(|d| *d <= tolerance)
+                    };
+                    assert!(close(splat(0.1).angle_diff(splat(6.2)), 0.1 - 6.2 + TAU));
+                    assert!(close(splat(6.2).angle_diff(splat(0.1)), 6.2 - 0.1 - TAU));
+                    // Crossing the `±π` boundary.
+                    assert!(close(splat(3.0).angle_diff(splat(-3.0)), 6.0 - TAU));
+                    assert!(close(splat(-3.0).angle_diff(splat(3.0)), TAU - 6.0));
+                    assert!(close(splat(1.0).angle_diff(splat(0.5)), 0.5));
+                    assert_eq!(splat(PI).wrap_pi(), splat(PI));
+                    assert!(close(splat(3.0 * PI).wrap_pi(), PI));
+                    let x = -PI / 2.0 - 4.0 * TAU;
+                    assert!(close(splat(x).wrap_pi(), x + 4.0 * TAU));
+                    assert!(splat($scalar::NAN).wrap_pi().is_nan().all());
+                    assert!(splat($scalar::INFINITY).wrap_pi().is_nan().all());
+
+                    test_helpers::test_2(&|a: [$scalar; LANES], b: [$scalar; LANES]| {
+                        let a = Simd::from_array(a.map(|x| x % 100.0));
+                        let b = Simd::from_array(b.map(|x| x % 100.0));
+                        let diff = a.angle_diff(b);
+                        for i in 0..LANES {
+                            if a[i].is_nan() || b[i].is_nan() {
+                                continue;
+                            }
+                            // The result is the minimal rotation: it is within `π` either way,
+                            // and differs from `a - b` by a whole number of turns.
+                            assert!(diff[i].abs() <= PI, "{}", diff[i]);
+                            let turns = (a[i] - b[i] - diff[i]) / TAU;
+                            assert!((turns - turns.round()).abs() <= 1e-4, "{turns}");
+                        }
+                        Ok(())
+                    });
+                }
+
+                fn lerp_angle<const LANES: usize>() {
+                    use core::$scalar::consts::{PI, TAU};
+                    use core_simd::simd::{num::SimdFloat, Simd};
+
+                    let splat = Simd::<$scalar, LANES>::splat;
+                    let close = |a: Simd<$scalar, LANES>, b: $scalar| {
+                        let tolerance = 8.0 * $scalar::EPSILON * (1.0 + b.abs());
+                        (a - splat(b)).abs().to_array().iter().all(|d| *d <= tolerance)
+                    };
+                    // The short way from `0.1` to `6.2` crosses zero, not `π`.
+                    let (a, b) = (splat(0.1), splat(6.2));
+                    assert!(close(a.lerp_angle(b, splat(0.5)), (0.1 + 6.2 - TAU) / 2.0));
+                    // Across the `±π` seam, the midpoint is `±π` rather than zero.
+                    let (a, b) = (splat(3.0), splat(-3.0));
+                    assert!(close(a.lerp_angle(b, splat(0.5)).angle_diff(splat(PI)), 0.0));
+                    assert!(close(a.lerp_angle(b, splat(0.25)), 3.0 + (TAU - 6.0) / 4.0));
+                    assert!(close(a.lerp_angle(b, splat(0.75)), -3.0 - (TAU - 6.0) / 4.0));
+                    assert!(splat($scalar::NAN).lerp_angle(b, splat(0.5)).is_nan().all());
+
+                    test_helpers::test_2(&|a: [$scalar; LANES], b: [$scalar; LANES]| {
+                        let a = Simd::from_array(a.map(|x| x % PI));
+                        let b = Simd::from_array(b.map(|x| x % PI));
+                        if a.is_nan().any() || b.is_nan().any() {
+                            return Ok(());
+                        }
+                        // The endpoints are exact.
+                        assert_eq!(a.lerp_angle(b, splat(0.0)), a);
+                        assert_eq!(a.lerp_angle(b, splat(1.0)), b);
+                        // Halfway, both ends are equally far away.
+                        let mid = a.lerp_angle(b, splat(0.5));
+                        let (to_a, to_b) = (mid.angle_diff(a).abs(), b.angle_diff(mid).abs());
+                        assert!(((to_a - to_b).abs()).reduce_max() <= 16.0 * $scalar::EPSILON);
+                        assert!((to_a + to_b).reduce_max() <= PI + 16.0 * $scalar::EPSILON);
+                        Ok(())
+                    });
+                }
+
+                fn wrap_range<const LANES: usize>() {
+                    use core_simd::simd::{num::SimdFloat, Simd};
+
+                    let splat = Simd::<$scalar, LANES>::splat;
+                    let (lo, hi) = (splat(-1.0), splat(3.0));
+                    assert_eq!(splat(-2.0).wrap_range(lo, hi), splat(2.0));
+                    assert_eq!(splat(3.0).wrap_range(lo, hi), splat(-1.0));
+                    assert_eq!(splat(4.5).wrap_range(lo, hi), splat(0.5));
+                    assert_eq!(splat(-1.0).wrap_range(lo, hi), splat(-1.0));
+                    assert_eq!(splat(1002.0).wrap_range(lo, hi), splat(2.0));
+                    assert_eq!(splat(-1001.0).wrap_range(lo, hi), splat(-1.0));
+                    // Just below `lo`, the exact result rounds to `hi`.
+                    let tiny = splat(-$scalar::EPSILON * $scalar::EPSILON);
+                    assert_eq!(tiny.wrap_range(splat(0.0), splat(1.0)), splat(0.0));
+                    assert_eq!(splat(5.0).wrap_range(splat(2.0), splat(2.0)), splat(2.0));
+                    assert!(splat($scalar::NAN).wrap_range(lo, hi).is_nan().all());
+                    assert!(splat($scalar::NAN).wrap_range(lo, lo).is_nan().all());
+                    assert!(splat($scalar::INFINITY).wrap_range(lo, hi).is_nan().all());
+
+                    test_helpers::test_3(&|a: [$scalar; LANES], b, c: [$scalar; LANES]| {
+                        let (a, b) = (Simd::from_array(a), Simd::from_array(b));
+                        let c = Simd::from_array(c);
+                        let (lo, hi) = (b.simd_min(c), b.simd_max(c));
+                        let wrapped = a.wrap_range(lo, hi);
+                        for i in 0..LANES {
+                            let width = hi[i] - lo[i];
+                            let offset = a[i] - lo[i];
+                            if !offset.is_finite() || !width.is_finite() || width == 0.0 {
+                                continue;
+                            }
+                            assert!(lo[i] <= wrapped[i] && wrapped[i] < hi[i], "{}", wrapped[i]);
+                        }
+                        Ok(())
+                    });
+                }
+
+                fn add_clamped<const LANES: usize>() {
+                    use core_simd::simd::{num::SimdFloat, Simd};
+
+                    let splat = Simd::<$scalar, LANES>::splat;
+                    let (min, max) = (splat(-1.0), splat(1.0));
+                    assert_eq!(splat(0.25).add_clamped(splat(0.5), min, max), splat(0.75));
+                    assert_eq!(splat(0.75).add_clamped(splat(0.5), min, max), max);
+                    assert_eq!(splat(-0.75).add_clamped(splat(-0.5), min, max), min);
+                    assert_eq!(splat($scalar::MAX).add_clamped(splat($scalar::MAX), min, max), max);
+                    let nan = splat($scalar::NAN);
+                    assert!(nan.add_clamped(splat(0.5), min, max).is_nan().all());
+                    let inf = splat($scalar::INFINITY);
+                    assert!(inf.add_clamped(-inf, min, max).is_nan().all());
+
+                    // Bounds are treated as by `clamp`, rather than panicking.
+                    let one = splat(1.0);
+                    assert_eq!(splat(2.0).add_clamped(one, nan, max), max);
+                    assert_eq!(splat(-2.0).add_clamped(-one, min, nan), min);
+                    assert_eq!(splat(2.0).add_clamped(one, nan, nan), splat(3.0));
+                    assert_eq!(splat(0.0).add_clamped(one, max, min), min);
+
+                    test_helpers::test_3(&|a: [$scalar; LANES], b, c: [$scalar; LANES]| {
+                        let (a, b) = (Simd::from_array(a), Simd::from_array(b));
+                        let c = Simd::from_array(c);
+                        // Including `NaN` and reversed bounds.
+                        let (min, max) = (c, -c);
+                        let clamped = a.add_clamped(b, min, max);
+                        let composed = StdFloat::clamp(a + b, min, max);
+                        assert_eq!(clamped.to_bits(), composed.to_bits());
+                        Ok(())
+                    });
+                }
+
+                fn exp_clamped<const LANES: usize>() {
+                    use core_simd::simd::{num::SimdFloat, Simd};
+
+                    let splat = Simd::<$scalar, LANES>::splat;
+                    let max = splat(1e6);
+                    // Large enough that `exp` overflows.
+                    for x in [1000.0, $scalar::MAX, $scalar::INFINITY] {
+                        assert_eq!(splat(x).exp(), splat($scalar::INFINITY));
+                        assert_eq!(splat(x).exp_clamped(max), max);
+                    }
+                    assert_eq!(splat(20.0).exp_clamped(max), max);
+                    assert_eq!(splat($scalar::NEG_INFINITY).exp_clamped(max), splat(0.0));
+                    assert!(splat($scalar::NAN).exp_clamped(max).is_nan().all());
+
+                    // Results below the limit are those of `exp`.
+                    test_helpers::test_1(&|x: [$scalar; LANES]| {
+                        let x = Simd::from_array(x);
+                        let exp = x.exp();
+                        let clamped = x.exp_clamped(max);
+                        for i in 0..LANES {
+                            if exp[i] <= max[i] || exp[i].is_nan() {
+                                assert_eq!(clamped[i].to_bits(), exp[i].to_bits());
+                            } else {
+                                assert_eq!(clamped[i], max[i]);
+                            }
+                        }
+                        Ok(())
+                    });
+                }
+
+                fn round_half<const LANES: usize>() {
+                    use core_simd::simd::{num::SimdFloat, Simd};
+
+                    // (value, away from zero, toward +inf, toward -inf)
+                    let cases: [($scalar, $scalar, $scalar, $scalar); 10] = [
+                        (2.5, 3.0, 3.0, 2.0),
+                        (-2.5, -3.0, -2.0, -3.0),
+                        (3.5, 4.0, 4.0, 3.0),
+                        (-3.5, -4.0, -3.0, -4.0),
+                        (0.5, 1.0, 1.0, 0.0),
+                        (-0.5, -1.0, -0.0, -1.0),
+                        (2.4, 2.0, 2.0, 2.0),
+                        (-2.6, -3.0, -3.0, -3.0),
+                        (0.5 - $scalar::EPSILON / 4.0, 0.0, 0.0, 0.0),
+                        ($scalar::INFINITY, $scalar::INFINITY, $scalar::INFINITY, $scalar::INFINITY),
+                    ];
+                    for (x, away, up, down) in cases {
+                        let x = Simd::<$scalar, LANES>::splat(x);
+                        assert_eq!(x.round_half_away().to_bits(), Simd::splat(away).to_bits(), "{x:?}");
+                        assert_eq!(x.round_half_up().to_bits(), Simd::splat(up).to_bits(), "{x:?}");
+                        assert_eq!(x.round_half_down().to_bits(), Simd::splat(down).to_bits(), "{x:?}");
+                    }
+
+                    let nan = Simd::<$scalar, LANES>::splat($scalar::NAN);
+                    assert!(nan.round_half_up().is_nan().all());
+                    assert!(nan.round_half_down().is_nan().all());
+                }
             }
         }
     }
@@ -0,0 +1,537 @@
+#![feature(portable_simd)]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+use core_simd::simd::{f32x4, num::SimdFloat, Simd};
+use std_float::{
+    barycentric, clamp_length3, cross3, dot3, dot4, mat4_transform, normalize3, project3, quat_mul,
+    reflect3, reject3, slerp, spherical_to_cartesian,
+};
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn dot_known() {
+    let a = f32x4::from_array([1.0, 2.0, 3.0, 4.0]);
+    let b = f32x4::from_array([5.0, -6.0, 7.0, 8.0]);
+    assert_eq!(dot4(a, b), 5.0 - 12.0 + 21.0 + 32.0);
+    assert_eq!(dot3(a, b), 5.0 - 12.0 + 21.0);
+
+    let w = f32x4::from_array([1.0, 2.0, 3.0, f32::NAN]);
+    assert_eq!(dot3(w, b), 5.0 - 12.0 + 21.0);
+    assert!(dot4(w, b).is_nan());
+    let w = f32x4::from_array([1.0, 2.0, 3.0, f32::INFINITY]);
+    assert_eq!(dot3(w, f32x4::splat(0.0)), 0.0);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn dot_matches_reduction() {
+    test_helpers::test_2(&|a: [f32; 4], b: [f32; 4]| {
+        // Keep the products finite, so that they can be compared with a relative tolerance.
+        let a = f32x4::from_array(a.map(|x| x % 1e6));
+        let b = f32x4::from_array(b.map(|x| x % 1e6));
+        let p = a * b;
+        let (dot, expected) = (dot4(a, b), p.reduce_sum());
+        if expected.is_nan() {
+            assert!(dot.is_nan());
+            return Ok(());
+        }
+        // Summing in a different order can only change the rounding of the partial sums.
+        let tolerance = 4.0 * f32::EPSILON * p.abs().reduce_sum();
+        assert!((dot - expected).abs() <= tolerance, "{dot} != {expected}");
+
+        let xyz = f32x4::from_array([p[0], p[1], p[2], 0.0]);
+        let (dot, expected) = (dot3(a, b), xyz.reduce_sum());
+        let tolerance = 4.0 * f32::EPSILON * xyz.abs().reduce_sum();
+        assert!((dot - expected).abs() <= tolerance, "{dot} != {expected}");
+        Ok(())
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn cross_known() {
+    let x = f32x4::from_array([1.0, 0.0, 0.0, 0.0]);
+    let y = f32x4::from_array([0.0, 1.0, 0.0, 0.0]);
+    let z = f32x4::from_array([0.0, 0.0, 1.0, 0.0]);
+    assert_eq!(cross3(x, y), z);
+    assert_eq!(cross3(y, z), x);
+    assert_eq!(cross3(z, x), y);
+    assert_eq!(cross3(y, x), -z);
+
+    let a = f32x4::from_array([1.0, 2.0, 3.0, f32::NAN]);
+    let b = f32x4::from_array([4.0, 5.0, 6.0, f32::INFINITY]);
+    assert_eq!(cross3(a, b), f32x4::from_array([-3.0, 6.0, -3.0, 0.0]));
+    assert_eq!(cross3(a, a), f32x4::splat(0.0));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn cross_is_anticommutative_and_orthogonal() {
+    test_helpers::test_2(&|a: [f32; 4], b: [f32; 4]| {
+        let a = f32x4::from_array(a.map(|x| x % 1e3));
+        let b = f32x4::from_array(b.map(|x| x % 1e3));
+        let (ab, ba) = (cross3(a, b), cross3(b, a));
+        if ab.is_nan().any() {
+            return Ok(());
+        }
+        assert_eq!(ab, -ba);
+        assert_eq!(ab[3], 0.0);
+        // The cross product is orthogonal to both inputs, up to rounding.
+        let norm = |v: f32x4| v[0].abs() + v[1].abs() + v[2].abs();
+        let scale = norm(a) * norm(a) * norm(b) + norm(a) * norm(b) * norm(b);
+        assert!(dot3(ab, a).abs() <= 8.0 * f32::EPSILON * scale);
+        assert!(dot3(ab, b).abs() <= 8.0 * f32::EPSILON * scale);
+        Ok(())
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn normalize_known() {
+    let v = f32x4::from_array([3.0, 0.0, -4.0, 9.0]);
+    let n = normalize3(v);
+    assert!(
+        (n - f32x4::from_array([0.6, 0.0, -0.8, 0.0]))
+            .abs()
+            .reduce_max()
+            <= f32::EPSILON
+    );
+    assert_eq!(n[3], 0.0);
+    assert_eq!(normalize3(f32x4::splat(0.0)), f32x4::splat(0.0));
+    assert_eq!(
+        normalize3(f32x4::from_array([0.0, 0.0, 0.0, 1.0])),
+        f32x4::splat(0.0)
+    );
+    assert_eq!(
+        normalize3(f32x4::from_array([0.0, 1e-30, 0.0, 0.0])),
+        f32x4::splat(0.0)
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn clamp_length_known() {
+    // Too long: scaled to the maximum length, keeping the fourth lane.
+    let v = f32x4::from_array([3.0, 0.0, -4.0, 9.0]);
+    let clamped = clamp_length3(v, 2.5);
+    assert_eq!(clamped, f32x4::from_array([1.5, 0.0, -2.0, 9.0]));
+    assert_eq!(dot3(clamped, clamped), 2.5 * 2.5);
+
+    // Short enough: unchanged.
+    assert_eq!(clamp_length3(v, 5.0), v);
+    assert_eq!(clamp_length3(v, 10.0), v);
+    let short = f32x4::from_array([0.1, -0.2, 0.3, -9.0]);
+    assert_eq!(clamp_length3(short, 1.0), short);
+
+    // The zero vector stays zero, even with no length allowed.
+    assert_eq!(clamp_length3(f32x4::splat(0.0), 1.0), f32x4::splat(0.0));
+    assert_eq!(clamp_length3(f32x4::splat(0.0), 0.0), f32x4::splat(0.0));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn clamp_length_bounds_length() {
+    test_helpers::test_1(&|v: [f32; 4]| {
+        // Keep the squared length a normal `f32`.
+        let v = f32x4::from_array(v.map(|x| x % 1e6));
+        if v.is_nan().any() || dot3(v, v) < f32::MIN_POSITIVE {
+            return Ok(());
+        }
+        let max_len = 1.0;
+        let clamped = clamp_length3(v, max_len);
+        assert!(dot3(clamped, clamped).sqrt() <= max_len * (1.0 + 4.0 * f32::EPSILON));
+        assert_eq!(clamped[3].to_bits(), v[3].to_bits());
+        // The direction is unchanged.
+        assert!(cross3(clamped, v).abs().reduce_max() <= 4.0 * f32::EPSILON * v.abs().reduce_max());
+        Ok(())
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn normalize_has_unit_length() {
+    test_helpers::test_1(&|v: [f32; 4]| {
+        // Keep the squared length a normal `f32`.
+        let v = f32x4::from_array(v.map(|x| x % 1e6));
+        if v.is_nan().any() || dot3(v, v) < f32::MIN_POSITIVE {
+            return Ok(());
+        }
+        let n = normalize3(v);
+        assert!((dot3(n, n) - 1.0).abs() <= 4.0 * f32::EPSILON, "{n:?}");
+        assert_eq!(n[3], 0.0);
+        // The direction is unchanged.
+        assert!(cross3(n, v).abs().reduce_max() <= 4.0 * f32::EPSILON * v.abs().reduce_max());
+        Ok(())
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn reflect_known() {
+    let i = f32x4::from_array([1.0, -2.0, 3.0, 7.0]);
+    let x = f32x4::from_array([1.0, 0.0, 0.0, 0.0]);
+    let y = f32x4::from_array([0.0, -1.0, 0.0, 0.0]);
+    assert_eq!(reflect3(i, x), f32x4::from_array([-1.0, -2.0, 3.0, 0.0]));
+    assert_eq!(reflect3(i, y), f32x4::from_array([1.0, 2.0, 3.0, 0.0]));
+    // A vector parallel to the surface is unchanged.
+    let along = f32x4::from_array([0.0, 0.0, 5.0, 0.0]);
+    assert_eq!(reflect3(along, x), along);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn reflect_preserves_geometry() {
+    test_helpers::test_2(&|i: [f32; 4], n: [f32; 4]| {
+        let i = f32x4::from_array(i.map(|x| x % 1e3));
+        let n = f32x4::from_array(n.map(|x| x % 1e3));
+        if i.is_nan().any() || n.is_nan().any() {
+            return Ok(());
+        }
+        // Keep the squared lengths normal `f32`s, so that `n` normalizes accurately and the
+        // tolerances below are relative.
+        if dot3(i, i) < f32::MIN_POSITIVE || dot3(n, n) < f32::MIN_POSITIVE {
+            return Ok(());
+        }
+        let n = normalize3(n);
+        let r = reflect3(i, n);
+        let scale = dot3(i.abs(), i.abs());
+        // The length is preserved, and the component along the normal is negated.
+        assert!((dot3(r, r) - dot3(i, i)).abs() <= 16.0 * f32::EPSILON * scale);
+        let tolerance = 16.0 * f32::EPSILON * scale.sqrt();
+        assert!((dot3(r, n) + dot3(i, n)).abs() <= tolerance);
+        // Reflecting twice gives back the incident vector.
+        let back = reflect3(r, n) - f32x4::from_array([i[0], i[1], i[2], 0.0]);
+        assert!(back.abs().reduce_max() <= tolerance);
+        Ok(())
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn projection_known() {
+    let a = f32x4::from_array([3.0, 4.0, 5.0, 6.0]);
+    let x = f32x4::from_array([2.0, 0.0, 0.0, 0.0]);
+    assert_eq!(project3(a, x), f32x4::from_array([3.0, 0.0, 0.0, 0.0]));
+    assert_eq!(reject3(a, x), f32x4::from_array([0.0, 4.0, 5.0, 0.0]));
+    let zero = f32x4::splat(0.0);
+    assert_eq!(project3(a, zero), zero);
+    assert_eq!(reject3(a, zero), f32x4::from_array([3.0, 4.0, 5.0, 0.0]));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn projection_decomposes() {
+    test_helpers::test_2(&|a: [f32; 4], b: [f32; 4]| {
+        let a = f32x4::from_array(a.map(|x| x % 1e3));
+        let b = f32x4::from_array(b.map(|x| x % 1e3));
+        // Keep the squared lengths normal `f32`s, so that the tolerances below are relative.
+        if a.is_nan().any() || b.is_nan().any() {
+            return Ok(());
+        }
+        if dot3(a, a) < f32::MIN_POSITIVE || dot3(b, b) < f32::MIN_POSITIVE {
+            return Ok(());
+        }
+        let (p, r) = (project3(a, b), reject3(a, b));
+        let a_xyz = f32x4::from_array([a[0], a[1], a[2], 0.0]);
+        let scale = a_xyz.abs().reduce_max();
+        assert!((p + r - a_xyz).abs().reduce_max() <= 4.0 * f32::EPSILON * scale);
+        // The rejection is orthogonal to `b`, and the projection is parallel to it.
+        let tolerance = 16.0 * f32::EPSILON * scale * dot3(b, b).sqrt();
+        assert!(dot3(r, b).abs() <= tolerance);
+        assert!(cross3(p, b).abs().reduce_max() <= tolerance);
+        Ok(())
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn barycentric_known() {
+    let a = f32x4::from_array([1.0, -2.0, 0.5, 7.0]);
+    let b = f32x4::from_array([4.0, 1.0, -3.0, 0.0]);
+    let c = f32x4::from_array([-2.0, 5.0, 2.0, -1.0]);
+    assert_eq!(barycentric(a, a, b, c), (1.0, 0.0, 0.0));
+    assert_eq!(barycentric(b, a, b, c), (0.0, 1.0, 0.0));
+    assert_eq!(barycentric(c, a, b, c), (0.0, 0.0, 1.0));
+
+    let centroid = (a + b + c) / f32x4::splat(3.0);
+    let (u, v, w) = barycentric(centroid, a, b, c);
+    for weight in [u, v, w] {
+        assert!(
+            (weight - 1.0 / 3.0).abs() <= 4.0 * f32::EPSILON,
+            "{:?}",
+            (u, v, w)
+        );
+    }
+
+    // A point off the plane gets the weights of its projection onto the plane.
+    let a = f32x4::from_array([0.0, 0.0, 0.0, 0.0]);
+    let b = f32x4::from_array([1.0, 0.0, 0.0, 0.0]);
+    let c = f32x4::from_array([0.0, 1.0, 0.0, 0.0]);
+    let p = f32x4::from_array([0.25, 0.5, 3.0, 0.0]);
+    assert_eq!(barycentric(p, a, b, c), (0.25, 0.25, 0.5));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn barycentric_degenerate() {
+    let a = f32x4::from_array([1.0, 2.0, 3.0, 0.0]);
+    let b = f32x4::from_array([2.0, 4.0, 6.0, 0.0]);
+    let p = f32x4::from_array([0.0, 1.0, 0.0, 0.0]);
+    for (a, b, c) in [(a, a, a), (a, b, b), (a, b, a + a + a)] {
+        let (u, v, w) = barycentric(p, a, b, c);
+        assert!(u.is_nan() && v.is_nan() && w.is_nan());
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn mat4_transform_known() {
+    let identity = [
+        f32x4::from_array([1.0, 0.0, 0.0, 0.0]),
+        f32x4::from_array([0.0, 1.0, 0.0, 0.0]),
+        f32x4::from_array([0.0, 0.0, 1.0, 0.0]),
+        f32x4::from_array([0.0, 0.0, 0.0, 1.0]),
+    ];
+    let v = f32x4::from_array([1.5, -2.0, 3.25, 1.0]);
+    assert_eq!(mat4_transform(identity, v), v);
+
+    // Scale by `(2, 3, 4)`, then translate by `(10, 20, 30)`.
+    let transform = [
+        f32x4::from_array([2.0, 0.0, 0.0, 10.0]),
+        f32x4::from_array([0.0, 3.0, 0.0, 20.0]),
+        f32x4::from_array([0.0, 0.0, 4.0, 30.0]),
+        f32x4::from_array([0.0, 0.0, 0.0, 1.0]),
+    ];
+    let point = f32x4::from_array([1.0, -1.0, 0.5, 1.0]);
+    assert_eq!(
+        mat4_transform(transform, point),
+        f32x4::from_array([12.0, 17.0, 32.0, 1.0])
+    );
+    // Directions are scaled but not translated.
+    let direction = f32x4::from_array([1.0, -1.0, 0.5, 0.0]);
+    assert_eq!(
+        mat4_transform(transform, direction),
+        f32x4::from_array([2.0, -3.0, 2.0, 0.0])
+    );
+
+    // Each row gives one lane of the result.
+    let rows = [
+        f32x4::from_array([1.0, 2.0, 3.0, 4.0]),
+        f32x4::from_array([5.0, 6.0, 7.0, 8.0]),
+        f32x4::from_array([9.0, 10.0, 11.0, 12.0]),
+        f32x4::from_array([13.0, 14.0, 15.0, 16.0]),
+    ];
+    let v = f32x4::from_array([1.0, 0.0, -1.0, 2.0]);
+    assert_eq!(
+        mat4_transform(rows, v),
+        f32x4::from_array([6.0, 14.0, 22.0, 30.0])
+    );
+}
+
+/// Returns the unit quaternion rotating by `angle` radians about the unit vector `axis`.
+fn rotation(axis: [f32; 3], angle: f32) -> f32x4 {
+    let (sin, cos) = (angle / 2.0).sin_cos();
+    f32x4::from_array([axis[0] * sin, axis[1] * sin, axis[2] * sin, cos])
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn quat_mul_known() {
+    let [i, j, k, one] = [0, 1, 2, 3].map(|n| {
+        let mut q = [0.0; 4];
+        q[n] = 1.0;
+        f32x4::from_array(q)
+    });
+    // i * j = k, j * k = i, k * i = j, and the reversed products are negated.
+    assert_eq!(quat_mul(i, j), k);
+    assert_eq!(quat_mul(j, k), i);
+    assert_eq!(quat_mul(k, i), j);
+    assert_eq!(quat_mul(j, i), -k);
+    assert_eq!(quat_mul(k, j), -i);
+    assert_eq!(quat_mul(i, k), -j);
+    // i² = j² = k² = ijk = -1.
+    for q in [i, j, k] {
+        assert_eq!(quat_mul(q, q), -one);
+    }
+    assert_eq!(quat_mul(quat_mul(i, j), k), -one);
+
+    let a = f32x4::from_array([1.0, 2.0, 3.0, 4.0]);
+    let b = f32x4::from_array([5.0, 6.0, 7.0, 8.0]);
+    assert_eq!(quat_mul(a, b), f32x4::from_array([24.0, 48.0, 48.0, -6.0]));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn quat_mul_composes_rotations() {
+    use core::f32::consts::{FRAC_PI_2, PI};
+
+    let close = |a: f32x4, b: f32x4| (a - b).abs().reduce_max() <= 4.0 * f32::EPSILON;
+
+    // Two quarter turns about an axis make a half turn.
+    for axis in [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]] {
+        let quarter = rotation(axis, FRAC_PI_2);
+        let half = quat_mul(quarter, quarter);
+        assert!(close(half, rotation(axis, PI)), "{half:?}");
+    }
+
+    // A quarter turn about z takes the x axis to the y axis.
+    let q = rotation([0.0, 0.0, 1.0], FRAC_PI_2);
+    let conjugate = q * f32x4::from_array([-1.0, -1.0, -1.0, 1.0]);
+    let x = f32x4::from_array([1.0, 0.0, 0.0, 0.0]);
+    let rotated = quat_mul(quat_mul(q, x), conjugate);
+    assert!(
+        close(rotated, f32x4::from_array([0.0, 1.0, 0.0, 0.0])),
+        "{rotated:?}"
+    );
+
+    // Rotating about x and then about z is not the same as the other order.
+    let about_x = rotation([1.0, 0.0, 0.0], FRAC_PI_2);
+    assert!(!close(quat_mul(q, about_x), quat_mul(about_x, q)));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn quat_mul_identity() {
+    let identity = f32x4::from_array([0.0, 0.0, 0.0, 1.0]);
+    test_helpers::test_1(&|q: [f32; 4]| {
+        let q = f32x4::from_array(q);
+        if !q.is_finite().all() {
+            return Ok(());
+        }
+        assert_eq!(quat_mul(identity, q), q);
+        assert_eq!(quat_mul(q, identity), q);
+        Ok(())
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn slerp_known() {
+    use core::f32::consts::FRAC_PI_2;
+
+    let close = |a: f32x4, b: f32x4| (a - b).abs().reduce_max() <= 4.0 * f32::EPSILON;
+    let identity = rotation([0.0, 0.0, 1.0], 0.0);
+    let quarter = rotation([0.0, 0.0, 1.0], FRAC_PI_2);
+    for t in [0.25, 0.5, 0.75] {
+        let q = slerp(identity, quarter, t);
+        let expected = rotation([0.0, 0.0, 1.0], t * FRAC_PI_2);
+        assert!(close(q, expected), "{t}: {q:?}");
+    }
+    assert_eq!(slerp(identity, quarter, 0.0), identity);
+    assert_eq!(slerp(identity, quarter, 1.0), quarter);
+
+    // `-quarter` is the same rotation, so it takes the same path.
+    for t in [0.25, 0.5, 0.75] {
+        let q = slerp(identity, -quarter, t);
+        let expected = rotation([0.0, 0.0, 1.0], t * FRAC_PI_2);
+        assert!(close(q, expected), "{t}: {q:?}");
+    }
+    assert_eq!(slerp(identity, -quarter, 1.0), quarter);
+
+    // Nearly parallel inputs.
+    let tiny = rotation([0.0, 0.0, 1.0], 1e-3);
+    let q = slerp(identity, tiny, 0.5);
+    assert!(close(q, rotation([0.0, 0.0, 1.0], 0.5e-3)), "{q:?}");
+    assert_eq!(slerp(identity, identity, 0.5), identity);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn slerp_properties() {
+    test_helpers::test_3(&|a: [f32; 4], b: [f32; 4], t: [f32; 4]| {
+        let (a, b) = (f32x4::from_array(a), f32x4::from_array(b));
+        let t = t[0].abs() % 1.0;
+        // Keep the squared lengths normal `f32`s, so that the inputs normalize accurately.
+        let a = a % f32x4::splat(1e6);
+        let b = b % f32x4::splat(1e6);
+        if !(a.is_finite().all() && b.is_finite().all() && t.is_finite()) {
+            return Ok(());
+        }
+        if dot4(a, a) < f32::MIN_POSITIVE || dot4(b, b) < f32::MIN_POSITIVE {
+            return Ok(());
+        }
+        let a = a / f32x4::splat(dot4(a, a).sqrt());
+        let b = b / f32x4::splat(dot4(b, b).sqrt());
+
+        assert_eq!(slerp(a, b, 0.0), a);
+        let end = slerp(a, b, 1.0);
+        assert!(end == b || end == -b);
+
+        let q = slerp(a, b, t);
+        assert!((dot4(q, q) - 1.0).abs() <= 8.0 * f32::EPSILON, "{q:?}");
+        // The shorter arc never strays further from `a` than the end point does.
+        assert!(dot4(q, a) >= dot4(end, a) - 8.0 * f32::EPSILON, "{q:?}");
+        assert!(dot4(end, a) >= 0.0);
+        Ok(())
+    });
+}
+
+/// Converts points from Cartesian coordinates to spherical coordinates `(r, theta, phi)`, in the
+/// convention of `spherical_to_cartesian`, one element at a time.
+fn cartesian_to_spherical<const N: usize>(
+    x: Simd<f32, N>,
+    y: Simd<f32, N>,
+    z: Simd<f32, N>,
+) -> (Simd<f32, N>, Simd<f32, N>, Simd<f32, N>)
+where
+    core_simd::simd::LaneCount<N>: core_simd::simd::SupportedLaneCount,
+{
+    let (mut r, mut theta, mut phi) = ([0.0; N], [0.0; N], [0.0; N]);
+    for i in 0..N {
+        r[i] = (x[i] * x[i] + y[i] * y[i] + z[i] * z[i]).sqrt();
+        theta[i] = (z[i] / r[i]).acos();
+        phi[i] = y[i].atan2(x[i]);
+    }
+    (
+        Simd::from_array(r),
+        Simd::from_array(theta),
+        Simd::from_array(phi),
+    )
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn spherical_known() {
+    use core::f32::consts::{FRAC_PI_2, PI};
+
+    let close = |a: f32x4, b: [f32; 4]| (a - f32x4::from_array(b)).abs().reduce_max() <= 1e-6;
+    // The north pole, the south pole, and two points on the equator.
+    let r = f32x4::from_array([1.0, 2.0, 3.0, 4.0]);
+    let theta = f32x4::from_array([0.0, PI, FRAC_PI_2, FRAC_PI_2]);
+    let phi = f32x4::from_array([1.0, 1.0, 0.0, FRAC_PI_2]);
+    let (x, y, z) = spherical_to_cartesian(r, theta, phi);
+    assert!(close(x, [0.0, 0.0, 3.0, 0.0]), "{x:?}");
+    assert!(close(y, [0.0, 0.0, 0.0, 4.0]), "{y:?}");
+    assert!(close(z, [1.0, -2.0, 0.0, 0.0]), "{z:?}");
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn spherical_round_trip() {
+    use core::f32::consts::PI;
+
+    test_helpers::test_3(&|r: [f32; 4], theta: [f32; 4], phi: [f32; 4]| {
+        // Keep the angles within the ranges that `cartesian_to_spherical` produces, and away
+        // from the poles, where the azimuth is undefined.
+        let r = f32x4::from_array(r.map(|x| x.abs() % 1e3 + 1e-3));
+        let theta = f32x4::from_array(theta.map(|x| (x.abs() % (PI - 2e-3)) + 1e-3));
+        let phi = f32x4::from_array(phi.map(|x| x % PI));
+        if r.is_nan().any() || theta.is_nan().any() || phi.is_nan().any() {
+            return Ok(());
+        }
+        let (x, y, z) = spherical_to_cartesian(r, theta, phi);
+        let (r2, theta2, phi2) = cartesian_to_spherical(x, y, z);
+        assert!(((r2 - r) / r).abs().reduce_max() <= 1e-5, "{r:?} {r2:?}");
+        assert!(
+            (theta2 - theta).abs().reduce_max() <= 1e-3,
+            "{theta:?} {theta2:?}"
+        );
+        assert!((phi2 - phi).abs().reduce_max() <= 1e-3, "{phi:?} {phi2:?}");
+        Ok(())
+    });
+}
@@ -0,0 +1,148 @@
+//! Run with `--features libm_fallback`, so that the methods call `libm` instead of intrinsics
+//! and `std`, and check them against the scalar functions of `std`. The other tests also run with
+//! the feature, and cover the methods built on top of these.
+#![cfg(feature = "libm_fallback")]
+#![feature(portable_simd)]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+macro_rules! libm_fallback_tests {
+    { $scalar:ident } => {
+        mod $scalar {
+            use core_simd::simd::Simd;
+            use std_float::StdFloat;
+
+            /// Arguments in `[start, end]`, spaced geometrically away from zero so that small
+            /// magnitudes are covered too, with both signs if `start` is negative.
+            fn inputs(start: $scalar, end: $scalar) -> Vec<$scalar> {
+                let mut inputs = vec![0.0, start, end];
+                let mut x: $scalar = 1e-6;
+                while x < end {
+                    inputs.push(x);
+                    if start < 0.0 && -x >= start {
+                        inputs.push(-x);
+                    }
+                    x *= 1.01;
+                }
+                inputs
+            }
+
+            /// Asserts that `a` is within two units in the last place of `b`, which is the
+            /// documented difference between `libm` and the default implementations.
+            fn assert_close(a: $scalar, b: $scalar, x: $scalar) {
+                let ulp = b.abs().max($scalar::MIN_POSITIVE) * $scalar::EPSILON;
+                let close = a == b || (a - b).abs() <= 2.0 * ulp || a.is_nan() && b.is_nan();
+                assert!(close, "{x}: {a} != {b}");
+            }
+
+            fn check_unary(
+                method: fn(Simd<$scalar, 4>) -> Simd<$scalar, 4>,
+                scalar: fn($scalar) -> $scalar,
+                (start, end): ($scalar, $scalar),
+            ) {
+                let special = [$scalar::INFINITY, -$scalar::INFINITY, $scalar::NAN];
+                for x in inputs(start, end).into_iter().chain(special) {
+                    assert_close(method(Simd::splat(x))[0], scalar(x), x);
+                }
+            }
+
+            /// Asserts that `a` has the same bits as `b`, or that both are `NaN`.
+            fn assert_exact(a: $scalar, b: $scalar, x: $scalar) {
+                let same = a.to_bits() == b.to_bits() || a.is_nan() && b.is_nan();
+                assert!(same, "{x}: {a} != {b}");
+            }
+
+            #[test]
+            #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+            fn square_root_and_rounding() {
+                type Method = fn(Simd<$scalar, 4>) -> Simd<$scalar, 4>;
+                let methods: [(Method, fn($scalar) -> $scalar); 6] = [
+                    (StdFloat::sqrt, $scalar::sqrt),
+                    (StdFloat::ceil, $scalar::ceil),
+                    (StdFloat::floor, $scalar::floor),
+                    (StdFloat::round, $scalar::round),
+                    (StdFloat::trunc, $scalar::trunc),
+                    (StdFloat::fract, $scalar::fract),
+                ];
+                // Ties and the elements around them, as well as the usual inputs.
+                let ties = (-20..20).flat_map(|i| {
+                    let x = i as $scalar + 0.5;
+                    [x, x - $scalar::EPSILON, x + $scalar::EPSILON]
+                });
+                let special = [-0.0, $scalar::INFINITY, -$scalar::INFINITY, $scalar::NAN];
+                for x in inputs(-1e30, 1e30).into_iter().chain(ties).chain(special) {
+                    for (method, scalar) in methods {
+                        assert_exact(method(Simd::splat(x))[0], scalar(x), x);
+                    }
+                }
+            }
+
+            #[test]
+            #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+            fn fused_multiply_add() {
+                // Products whose low bits only survive with a single rounding.
+                let eps = $scalar::EPSILON;
+                let factors = [1.0 + eps, 1.0 - eps / 2.0, -3.0, 1e-3, 1e30, $scalar::INFINITY];
+                let addends = [-1.0, -(1.0 + 2.0 * eps), 0.0, -0.0, 7.5, $scalar::NAN];
+                let splat = Simd::<$scalar, 4>::splat;
+                for x in factors {
+                    for a in factors {
+                        for b in addends {
+                            let (v, a_v, b_v) = (splat(x), splat(a), splat(b));
+                            assert_exact(v.mul_add(a_v, b_v)[0], x.mul_add(a, b), x);
+                            assert_exact(v.mul_sub(a_v, b_v)[0], x.mul_add(a, -b), x);
+                            assert_exact(v.neg_mul_add(a_v, b_v)[0], (-x).mul_add(a, b), x);
+                            assert_exact(v.neg_mul_sub(a_v, b_v)[0], (-x).mul_add(a, -b), x);
+                        }
+                    }
+                }
+            }
+
+            #[test]
+            #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+            fn exponential_and_logarithmic() {
+                check_unary(StdFloat::exp, $scalar::exp, (-80.0, 80.0));
+                check_unary(StdFloat::exp2, $scalar::exp2, (-120.0, 120.0));
+                check_unary(StdFloat::ln, $scalar::ln, (-1.0, 1e30));
+                check_unary(StdFloat::log2, $scalar::log2, (-1.0, 1e30));
+                check_unary(StdFloat::log10, $scalar::log10, (-1.0, 1e30));
+            }
+
+            #[test]
+            #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+            fn trigonometric() {
+                check_unary(StdFloat::sin, $scalar::sin, (-1e4, 1e4));
+                check_unary(StdFloat::cos, $scalar::cos, (-1e4, 1e4));
+                check_unary(StdFloat::asin, $scalar::asin, (-1.5, 1.5));
+                check_unary(StdFloat::acos, $scalar::acos, (-1.5, 1.5));
+                check_unary(StdFloat::atan, $scalar::atan, (-1e10, 1e10));
+                for y in inputs(-1e3, 1e3) {
+                    for x in [-2.5, -0.0, 0.0, 1e-3, 7.0] {
+                        let atan2 = Simd::<$scalar, 4>::splat(y).atan2(Simd::splat(x))[0];
+                        assert_close(atan2, y.atan2(x), y);
+                    }
+                }
+            }
+
+            #[test]
+            #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+            fn powf_real() {
+                for base in inputs(-1e3, 1e3) {
+                    for exp in [-2.5, -1.0, 0.5, 1.0 / 3.0, 3.0] {
+                        let pow = Simd::<$scalar, 4>::splat(base).powf_real(Simd::splat(exp))[0];
+                        let odd_root = base < 0.0 && exp == 1.0 / 3.0;
+                        let expected = if odd_root { -(-base).powf(exp) } else { base.powf(exp) };
+                        assert_close(pow, expected, base);
+                    }
+                }
+            }
+        }
+    }
+}
+
+libm_fallback_tests! { f32 }
+libm_fallback_tests! { f64 }
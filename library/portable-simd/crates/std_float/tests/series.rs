@@ -0,0 +1,85 @@
+#![feature(portable_simd)]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+use core_simd::simd::{f64x4, Simd};
+use std_float::cheby_eval;
+
+/// Points spread over `[-1, 1]`, including both ends.
+fn points() -> impl Iterator<Item = f64> {
+    (-100..=100).map(|i| i as f64 / 100.0)
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn cheby_eval_known() {
+    let x = f64x4::from_array([-1.0, -0.5, 0.25, 1.0]);
+    assert_eq!(cheby_eval(x, &[]), f64x4::splat(0.0));
+    assert_eq!(cheby_eval(x, &[2.5]), f64x4::splat(2.5));
+    assert_eq!(cheby_eval(x, &[0.0, 1.0]), x);
+    // `T₂(x) = 2x² - 1`.
+    assert_eq!(
+        cheby_eval(x, &[0.0, 0.0, 1.0]),
+        f64x4::from_array([1.0, -0.5, -0.875, 1.0])
+    );
+
+    // `x³ = (3 T₁(x) + T₃(x)) / 4`.
+    for x in points() {
+        let cube = cheby_eval(Simd::<f64, 1>::splat(x), &[0.0, 0.75, 0.0, 0.25])[0];
+        assert!(
+            (cube - x * x * x).abs() <= 2.0 * f64::EPSILON,
+            "{x}: {cube}"
+        );
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn cheby_eval_exp() {
+    // `exp(x) = I₀(1) + 2 Σ Iₖ(1) Tₖ(x)`, where `Iₖ` is the modified Bessel function of the first
+    // kind, given by the power series `Iₖ(1) = Σ (1/2)^(2m + k) / (m! (m + k)!)`.
+    let bessel = |k: i32| {
+        let mut term = 0.5f64.powi(k) / (1..=k).map(f64::from).product::<f64>();
+        let mut sum = 0.0;
+        for m in 1..30 {
+            sum += term;
+            term *= 0.25 / (m as f64 * (m + k) as f64);
+        }
+        sum
+    };
+    let coeffs: Vec<f64> = (0..20)
+        .map(|k| if k == 0 { bessel(0) } else { 2.0 * bessel(k) })
+        .collect();
+    for x in points() {
+        let exp = cheby_eval(Simd::<f64, 8>::splat(x), &coeffs)[0];
+        let error = (exp - x.exp()).abs() / x.exp();
+        assert!(error <= 4.0 * f64::EPSILON, "{x}: {exp} != {}", x.exp());
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn cheby_eval_matches_direct_sum() {
+    // `Tₖ(cos θ) = cos(kθ)`, so the series can also be summed term by term.
+    let coeffs = [0.3, -1.2, 0.7, 2.0, -0.4, 0.05, 1.5, -0.9];
+    let x = Simd::<f64, 16>::from_array(core::array::from_fn(|i| (i as f64 / 7.5) - 1.0));
+    let series = cheby_eval(x, &coeffs);
+    for i in 0..16 {
+        let theta = x[i].acos();
+        let direct: f64 = coeffs
+            .iter()
+            .enumerate()
+            .map(|(k, c)| c * (k as f64 * theta).cos())
+            .sum();
+        assert!(
+            (series[i] - direct).abs() <= 1e-13,
+            "{}: {} != {direct}",
+            x[i],
+            series[i]
+        );
+    }
+}
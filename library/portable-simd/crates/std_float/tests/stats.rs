@@ -0,0 +1,129 @@
+#![feature(portable_simd)]
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+use core_simd::simd::{f64x1, f64x4, f64x8};
+use std_float::{count_finite, count_nan, mean, reduce_median, reduce_sorted, variance};
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn mean_known() {
+    assert_eq!(mean(f64x4::from_array([1.0, 2.0, 3.0, 4.0])), 2.5);
+    assert_eq!(mean(f64x4::splat(-7.5)), -7.5);
+    assert_eq!(
+        mean(f64x8::from_array([2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0])),
+        5.0
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn variance_known() {
+    // The classic example with a population standard deviation of exactly 2.
+    assert_eq!(
+        variance(f64x8::from_array([2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0])),
+        4.0
+    );
+    assert_eq!(variance(f64x4::from_array([1.0, 2.0, 3.0, 4.0])), 1.25);
+    assert_eq!(variance(f64x4::splat(3.0)), 0.0);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn variance_large_offset() {
+    // Shifting the data must not change its variance. The naive `E[x²] - E[x]²` formula
+    // returns garbage here, as `x²` is around 1e18 while the variance is around 0.2.
+    assert_eq!(
+        variance(f64x4::from_array([
+            1e9 + 4.0,
+            1e9 + 7.0,
+            1e9 + 13.0,
+            1e9 + 16.0
+        ])),
+        22.5
+    );
+
+    let v = variance(f64x4::from_array([
+        1e9 + 0.4,
+        1e9 + 0.7,
+        1e9 + 1.3,
+        1e9 + 1.6,
+    ]));
+    assert!((v - 0.225).abs() < 1e-6, "{v} != 0.225");
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sorted_known() {
+    use core_simd::simd::{f64x1, f64x16, f64x2};
+
+    assert_eq!(reduce_sorted(f64x1::splat(3.0)), [3.0]);
+    assert_eq!(reduce_sorted(f64x2::from_array([2.0, 1.0])), [1.0, 2.0]);
+    assert_eq!(
+        reduce_sorted(f64x4::from_array([4.0, 3.0, 2.0, 1.0])),
+        [1.0, 2.0, 3.0, 4.0]
+    );
+    assert_eq!(
+        reduce_sorted(f64x8::from_array([
+            5.0, -1.0, 3.0, 3.0, 0.0, 9.0, -1.0, 2.0
+        ])),
+        [-1.0, -1.0, 0.0, 2.0, 3.0, 3.0, 5.0, 9.0]
+    );
+
+    let reversed = core::array::from_fn(|i| (15 - i) as f64);
+    let ascending = core::array::from_fn(|i| i as f64);
+    assert_eq!(reduce_sorted(f64x16::from_array(reversed)), ascending);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sorted_keeps_nan() {
+    // The order around a NaN is unspecified, but no element may be lost or duplicated.
+    let sorted = reduce_sorted(f64x4::from_array([3.0, f64::NAN, 1.0, 2.0]));
+    assert_eq!(sorted.iter().filter(|x| x.is_nan()).count(), 1);
+    for x in [1.0, 2.0, 3.0] {
+        assert_eq!(sorted.iter().filter(|&&y| y == x).count(), 1);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn median_known() {
+    use core_simd::simd::f64x1;
+
+    assert_eq!(reduce_median(f64x1::splat(3.0)), 3.0);
+    assert_eq!(reduce_median(f64x4::from_array([4.0, 1.0, 3.0, 2.0])), 2.5);
+    assert_eq!(reduce_median(f64x4::from_array([7.0, 7.0, 1.0, 7.0])), 7.0);
+    assert_eq!(
+        reduce_median(f64x8::from_array([
+            5.0, -1.0, 3.0, 3.0, 0.0, 9.0, -1.0, 2.0
+        ])),
+        2.5
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn count_known() {
+    let v = f64x8::from_array([
+        1.0,
+        f64::NAN,
+        -0.0,
+        f64::INFINITY,
+        -f64::NAN,
+        f64::MIN_POSITIVE / 2.0,
+        f64::NEG_INFINITY,
+        f64::NAN,
+    ]);
+    assert_eq!(count_nan(v), 3);
+    assert_eq!(count_finite(v), 3);
+
+    assert_eq!(count_nan(f64x4::splat(2.0)), 0);
+    assert_eq!(count_finite(f64x4::splat(2.0)), 4);
+    assert_eq!(count_nan(f64x1::splat(f64::NAN)), 1);
+    assert_eq!(count_finite(f64x1::splat(f64::NAN)), 0);
+}
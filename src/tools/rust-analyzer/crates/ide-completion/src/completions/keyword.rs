@@ -78,17 +78,23 @@ fn foo(a: A) { a.$0 }
             expect![[r#"
                 me into_future() (as IntoFuture) fn(self) -> <Self as IntoFuture>::IntoFuture
                 kw await                  expr.await
+                sn awaitunwrap            expr.await.unwrap()
                 sn box                    Box::new(expr)
+                sn boxdyn                 Box::new(expr) as Box<dyn Trait>
+                sn boxpin                 Box::pin(expr)
                 sn call                   function(expr)
                 sn dbg                    dbg!(expr)
                 sn dbgr                   dbg!(&expr)
+                sn dbgt                   eprintln!(expr: type = value)
                 sn deref                  *expr
+                sn ifmatch                if let pat = expr {}
                 sn let                    let
                 sn letm                   let mut
                 sn match                  match expr {}
                 sn ref                    &expr
                 sn refm                   &mut expr
                 sn return                 return expr
+                sn tryintou               expr.try_into().unwrap()
                 sn unsafe                 unsafe {}
             "#]],
         );
@@ -105,17 +111,22 @@ fn foo() {
             expect![[r#"
                 me into_future() (use core::future::IntoFuture) fn(self) -> <Self as IntoFuture>::IntoFuture
                 kw await                  expr.await
+                sn awaitunwrap            expr.await.unwrap()
                 sn box                    Box::new(expr)
+                sn boxpin                 Box::pin(expr)
                 sn call                   function(expr)
                 sn dbg                    dbg!(expr)
                 sn dbgr                   dbg!(&expr)
+                sn dbgt                   eprintln!(expr: type = value)
                 sn deref                  *expr
+                sn ifmatch                if let pat = expr {}
                 sn let                    let
                 sn letm                   let mut
                 sn match                  match expr {}
                 sn ref                    &expr
                 sn refm                   &mut expr
                 sn return                 return expr
+                sn tryintou               expr.try_into().unwrap()
                 sn unsafe                 unsafe {}
             "#]],
         );
@@ -135,16 +146,20 @@ fn foo(a: A) { a.$0 }
                 me into_future() (as IntoFuture) fn(self) -> <Self as IntoFuture>::IntoFuture
                 kw await                  expr.await
                 sn box                    Box::new(expr)
+                sn boxdyn                 Box::new(expr) as Box<dyn Trait>
                 sn call                   function(expr)
                 sn dbg                    dbg!(expr)
                 sn dbgr                   dbg!(&expr)
+                sn dbgt                   eprintln!(expr: type = value)
                 sn deref                  *expr
+                sn ifmatch                if let pat = expr {}
                 sn let                    let
                 sn letm                   let mut
                 sn match                  match expr {}
                 sn ref                    &expr
                 sn refm                   &mut expr
                 sn return                 return expr
+                sn tryintou               expr.try_into().unwrap()
                 sn unsafe                 unsafe {}
             "#]],
         );
@@ -2,15 +2,16 @@
 
 mod format_like;
 
-use hir::ItemInNs;
+use hir::{sym, ItemInNs};
 use ide_db::{
     documentation::{Documentation, HasDocs},
-    imports::insert_use::ImportScope,
+    imports::insert_use::{insert_use_glob, ImportScope},
     ty_filter::TryEnum,
     SnippetCap,
 };
 use stdx::never;
 use syntax::{
+    algo,
     ast::{self, make, AstNode, AstToken},
     SyntaxKind::{BLOCK_EXPR, EXPR_STMT, FOR_EXPR, IF_EXPR, LOOP_EXPR, STMT_LIST, WHILE_EXPR},
     TextRange, TextSize,
@@ -105,6 +106,29 @@ pub(crate) fn complete_postfix(
                     &format!("while let Ok($1) = {receiver_text} {{\n    $0\n}}"),
                 )
                 .add_to(acc, ctx.db);
+
+                postfix_snippet("toption", "expr.ok()", &format!("{receiver_text}.ok()"))
+                    .add_to(acc, ctx.db);
+                postfix_snippet("etoption", "expr.err()", &format!("{receiver_text}.err()"))
+                    .add_to(acc, ctx.db);
+
+                postfix_snippet("isok", "expr.is_ok()", &format!("{receiver_text}.is_ok()"))
+                    .add_to(acc, ctx.db);
+                postfix_snippet("iserr", "expr.is_err()", &format!("{receiver_text}.is_err()"))
+                    .add_to(acc, ctx.db);
+
+                postfix_snippet(
+                    "unwraperr",
+                    "expr.unwrap_err()",
+                    &format!("{receiver_text}.unwrap_err()"),
+                )
+                .add_to(acc, ctx.db);
+                postfix_snippet(
+                    "expecterr",
+                    "expr.expect_err(msg)",
+                    &format!("{receiver_text}.expect_err(\"$0\")"),
+                )
+                .add_to(acc, ctx.db);
             }
             TryEnum::Option => {
                 postfix_snippet(
@@ -127,6 +151,11 @@ pub(crate) fn complete_postfix(
                     &format!("while let Some($1) = {receiver_text} {{\n    $0\n}}"),
                 )
                 .add_to(acc, ctx.db);
+
+                postfix_snippet("issome", "expr.is_some()", &format!("{receiver_text}.is_some()"))
+                    .add_to(acc, ctx.db);
+                postfix_snippet("isnone", "expr.is_none()", &format!("{receiver_text}.is_none()"))
+                    .add_to(acc, ctx.db);
             }
         }
     } else if receiver_ty.is_bool() || receiver_ty.is_unknown() {
@@ -146,9 +175,328 @@ pub(crate) fn complete_postfix(
         }
     }
 
+    let is_iterator = impls_trait(ctx, receiver_ty, ctx.famous_defs().core_iter_Iterator());
+    if is_iterator {
+        postfix_snippet("find", "expr.find(|x| pred)", &format!("{receiver_text}.find(|$1| $0)"))
+            .add_to(acc, ctx.db);
+        postfix_snippet(
+            "position",
+            "expr.position(|x| pred)",
+            &format!("{receiver_text}.position(|$1| $0)"),
+        )
+        .add_to(acc, ctx.db);
+        postfix_snippet(
+            "takewhile",
+            "expr.take_while(|x| pred)",
+            &format!("{receiver_text}.take_while(|$1| $0)"),
+        )
+        .add_to(acc, ctx.db);
+        postfix_snippet(
+            "skipwhile",
+            "expr.skip_while(|x| pred)",
+            &format!("{receiver_text}.skip_while(|$1| $0)"),
+        )
+        .add_to(acc, ctx.db);
+        postfix_snippet(
+            "scan",
+            "expr.scan(init, |state, x| f)",
+            &format!("{receiver_text}.scan(${{1:init}}, |${{2:state}}, ${{3:x}}| $0)"),
+        )
+        .add_to(acc, ctx.db);
+        postfix_snippet("peekable", "expr.peekable()", &format!("{receiver_text}.peekable()"))
+            .add_to(acc, ctx.db);
+        postfix_snippet("stepby", "expr.step_by(step)", &format!("{receiver_text}.step_by($0)"))
+            .add_to(acc, ctx.db);
+        // `cycle` restarts the iterator from a clone of its initial state.
+        if impls_trait(ctx, receiver_ty, ctx.famous_defs().core_clone_Clone()) {
+            postfix_snippet("cycle", "expr.cycle()", &format!("{receiver_text}.cycle()"))
+                .add_to(acc, ctx.db);
+        }
+
+        if let Some(item_ty) = iterator_item_ty(ctx, receiver_ty) {
+            if impls_trait(ctx, &item_ty, ctx.famous_defs().core_cmp_Ord()) {
+                postfix_snippet("min", "expr.min()", &format!("{receiver_text}.min()"))
+                    .add_to(acc, ctx.db);
+                postfix_snippet("max", "expr.max()", &format!("{receiver_text}.max()"))
+                    .add_to(acc, ctx.db);
+            }
+
+            // Both `T: Sum<T>` and `T: Sum<&T>` exist, so the result type cannot be inferred from
+            // the items alone. Without an expected type, spell it out in a turbofish.
+            let result_ty = item_ty.strip_references();
+            let turbofish = match &ctx.expected_type {
+                Some(expected) if !expected.is_unknown() => String::new(),
+                _ => {
+                    let result_ty = result_ty
+                        .display_source_code(ctx.db, ctx.module.into(), true)
+                        .unwrap_or_else(|_| "_".to_owned());
+                    format!("::<${{1:{result_ty}}}>")
+                }
+            };
+            for (label, trait_) in [
+                ("sum", ctx.famous_defs().core_iter_Sum()),
+                ("product", ctx.famous_defs().core_iter_Product()),
+            ] {
+                let Some(trait_) = trait_ else { continue };
+                if result_ty.impls_trait(ctx.db, trait_, &[item_ty.clone()]) {
+                    postfix_snippet(
+                        label,
+                        &format!("expr.{label}()"),
+                        &format!("{receiver_text}.{label}{turbofish}()"),
+                    )
+                    .add_to(acc, ctx.db);
+                }
+            }
+        }
+    }
+
+    // Receivers that can only be turned into an iterator get an `into_iter()` call inserted.
+    let iterator_text = if is_iterator {
+        Some(receiver_text.clone())
+    } else if impls_trait(ctx, receiver_ty, ctx.famous_defs().core_iter_IntoIterator()) {
+        Some(format!("{receiver_text}.into_iter()"))
+    } else {
+        None
+    };
+    if let Some(iterator_text) = &iterator_text {
+        postfix_snippet("count", "expr.count()", &format!("{iterator_text}.count()"))
+            .add_to(acc, ctx.db);
+        postfix_snippet(
+            "flatmap",
+            "expr.flat_map(|x| iter)",
+            &format!("{iterator_text}.flat_map(|$1| $0)"),
+        )
+        .add_to(acc, ctx.db);
+    }
+
+    // Slices are mapped by reference rather than consumed.
+    let map_source = if !is_iterator && is_slice_like(ctx, receiver_ty) {
+        Some(format!("{receiver_text}.iter()"))
+    } else {
+        iterator_text
+    };
+    if let Some(map_source) = map_source {
+        postfix_snippet(
+            "mapcollect",
+            "expr.map(|x| y).collect()",
+            &format!("{map_source}.map(|$1| $0).collect::<Vec<_>>()"),
+        )
+        .add_to(acc, ctx.db);
+    }
+
+    if is_slice_like(ctx, receiver_ty) {
+        postfix_snippet("windows", "expr.windows(size)", &format!("{receiver_text}.windows($0)"))
+            .add_to(acc, ctx.db);
+        postfix_snippet("chunks", "expr.chunks(size)", &format!("{receiver_text}.chunks($0)"))
+            .add_to(acc, ctx.db);
+        postfix_snippet(
+            "splitfirst",
+            "expr.split_first()",
+            &format!("{receiver_text}.split_first()"),
+        )
+        .add_to(acc, ctx.db);
+        postfix_snippet("splitlast", "expr.split_last()", &format!("{receiver_text}.split_last()"))
+            .add_to(acc, ctx.db);
+        postfix_snippet(
+            "reversed",
+            "expr.iter().rev().collect()",
+            &format!("{receiver_text}.iter().rev().collect::<Vec<_>>()"),
+        )
+        .add_to(acc, ctx.db);
+    }
+
+    if let Some(element_ty) = slice_element_ty(ctx, receiver_ty) {
+        let clone = ctx.famous_defs().core_clone_Clone();
+        if clone.is_some_and(|clone| element_ty.impls_trait(ctx.db, clone, &[])) {
+            postfix_snippet("tovec", "expr.to_vec()", &format!("{receiver_text}.to_vec()"))
+                .add_to(acc, ctx.db);
+        }
+        if is_string(ctx, &element_ty) {
+            postfix_snippet("join", "expr.join(sep)", &format!("{receiver_text}.join($0)"))
+                .add_to(acc, ctx.db);
+        }
+        if is_string(ctx, &element_ty) || is_slice_like(ctx, &element_ty) {
+            postfix_snippet("concat", "expr.concat()", &format!("{receiver_text}.concat()"))
+                .add_to(acc, ctx.db);
+        }
+    }
+
+    if is_boxed_slice(ctx, receiver_ty) {
+        postfix_snippet("intovec", "expr.into_vec()", &format!("{receiver_text}.into_vec()"))
+            .add_to(acc, ctx.db);
+    }
+
+    if is_drainable(ctx, dot_receiver, receiver_ty) {
+        postfix_snippet("drain", "expr.drain(range)", &format!("{receiver_text}.drain($0)"))
+            .add_to(acc, ctx.db);
+    }
+
+    if is_map(ctx, receiver_ty) {
+        postfix_snippet(
+            "entry",
+            "expr.entry(key).or_insert(value)",
+            &format!("{receiver_text}.entry($1).or_insert($0)"),
+        )
+        .add_to(acc, ctx.db);
+    }
+
+    if is_entry(ctx, receiver_ty) {
+        postfix_snippet("ordefault", "expr.or_default()", &format!("{receiver_text}.or_default()"))
+            .add_to(acc, ctx.db);
+        postfix_snippet(
+            "orinsertwith",
+            "expr.or_insert_with(|| value)",
+            &format!("{receiver_text}.or_insert_with(|| $0)"),
+        )
+        .add_to(acc, ctx.db);
+    }
+
+    if is_string(ctx, receiver_ty) {
+        postfix_snippet("chars", "expr.chars()", &format!("{receiver_text}.chars()"))
+            .add_to(acc, ctx.db);
+        postfix_snippet("bytes", "expr.bytes()", &format!("{receiver_text}.bytes()"))
+            .add_to(acc, ctx.db);
+        postfix_snippet("lines", "expr.lines()", &format!("{receiver_text}.lines()"))
+            .add_to(acc, ctx.db);
+        postfix_snippet(
+            "charindices",
+            "for (i, c) in expr.char_indices() {}",
+            &format!("for (${{1:i}}, ${{2:c}}) in {receiver_text}.char_indices() {{\n    $0\n}}"),
+        )
+        .add_to(acc, ctx.db);
+        postfix_snippet("trim", "expr.trim()", &format!("{receiver_text}.trim()"))
+            .add_to(acc, ctx.db);
+        postfix_snippet("lower", "expr.to_lowercase()", &format!("{receiver_text}.to_lowercase()"))
+            .add_to(acc, ctx.db);
+        postfix_snippet("upper", "expr.to_uppercase()", &format!("{receiver_text}.to_uppercase()"))
+            .add_to(acc, ctx.db);
+        postfix_snippet("split", "expr.split(pat)", &format!("{receiver_text}.split($0)"))
+            .add_to(acc, ctx.db);
+        postfix_snippet(
+            "splitws",
+            "expr.split_whitespace()",
+            &format!("{receiver_text}.split_whitespace()"),
+        )
+        .add_to(acc, ctx.db);
+        postfix_snippet(
+            "replace",
+            "expr.replace(from, to)",
+            &format!("{receiver_text}.replace($1, $0)"),
+        )
+        .add_to(acc, ctx.db);
+        postfix_snippet("repeat", "expr.repeat(n)", &format!("{receiver_text}.repeat($0)"))
+            .add_to(acc, ctx.db);
+    }
+
+    if !receiver_ty.is_reference()
+        && impls_trait(ctx, receiver_ty, ctx.famous_defs().core_default_Default())
+    {
+        if let Ok(ty) = receiver_ty.display_source_code(ctx.db, ctx.module.into(), true) {
+            // Generic arguments would need a turbofish in a plain path.
+            let ty = if receiver_ty.as_adt().is_some()
+                && receiver_ty.type_arguments().next().is_none()
+            {
+                ty
+            } else {
+                format!("<{ty}>")
+            };
+            postfix_snippet("default", "Type::default()", &format!("{ty}::default()"))
+                .add_to(acc, ctx.db);
+        }
+    }
+
+    let numeric_ty = receiver_ty.strip_references().as_builtin();
+    if let Some(numeric_ty) = numeric_ty.filter(|it| it.is_float() || it.is_int() || it.is_uint()) {
+        // Methods can't be called on a literal whose type is still ambiguous.
+        if !is_unsuffixed_number(dot_receiver) {
+            // Unsigned integers have no `abs`.
+            if !numeric_ty.is_uint() {
+                postfix_snippet("abs", "expr.abs()", &format!("{receiver_text}.abs()"))
+                    .add_to(acc, ctx.db);
+            }
+            postfix_snippet("min", "expr.min(other)", &format!("{receiver_text}.min($0)"))
+                .add_to(acc, ctx.db);
+            postfix_snippet("max", "expr.max(other)", &format!("{receiver_text}.max($0)"))
+                .add_to(acc, ctx.db);
+        }
+
+        if !numeric_ty.is_float() {
+            for (label, spec) in [("hex", "x"), ("bin", "b"), ("oct", "o")] {
+                postfix_snippet(
+                    label,
+                    &format!("format!(\"{{:{spec}}}\", expr)"),
+                    &format!("format!(\"{{:{spec}}}\", {receiver_text})"),
+                )
+                .add_to(acc, ctx.db);
+            }
+            for (prefix, method) in
+                [("sat", "saturating"), ("checked", "checked"), ("wrapping", "wrapping")]
+            {
+                for op in ["add", "sub", "mul"] {
+                    postfix_snippet(
+                        &format!("{prefix}{op}"),
+                        &format!("expr.{method}_{op}(rhs)"),
+                        &format!("{receiver_text}.{method}_{op}($0)"),
+                    )
+                    .add_to(acc, ctx.db);
+                }
+            }
+        }
+    }
+
+    if is_string(ctx, receiver_ty) || is_slice_like(ctx, receiver_ty) {
+        postfix_snippet("contains", "expr.contains(x)", &format!("{receiver_text}.contains($0)"))
+            .add_to(acc, ctx.db);
+        postfix_snippet(
+            "startswith",
+            "expr.starts_with(x)",
+            &format!("{receiver_text}.starts_with($0)"),
+        )
+        .add_to(acc, ctx.db);
+        postfix_snippet("endswith", "expr.ends_with(x)", &format!("{receiver_text}.ends_with($0)"))
+            .add_to(acc, ctx.db);
+    }
+
     postfix_snippet("ref", "&expr", &format!("&{receiver_text}")).add_to(acc, ctx.db);
     postfix_snippet("refm", "&mut expr", &format!("&mut {receiver_text}")).add_to(acc, ctx.db);
     postfix_snippet("deref", "*expr", &format!("*{receiver_text}")).add_to(acc, ctx.db);
+    // Unlike `dbg` below, this never pulls in references around the receiver, so that a method
+    // chain can continue after the `dbg!` call.
+    if is_in_method_chain(dot_receiver) {
+        postfix_snippet("dbgc", "dbg!(expr) (chain)", &format!("dbg!({receiver_text})"))
+            .add_to(acc, ctx.db);
+    }
+    postfix_snippet(
+        "tryintou",
+        "expr.try_into().unwrap()",
+        &format!("{receiver_text}.try_into().unwrap()"),
+    )
+    .add_to(acc, ctx.db);
+    let fn_try_enum = enclosing_fn_try_enum(ctx, dot_receiver);
+    // `try_into` returns a `Result`, which `?` can't turn into an `Option`.
+    if matches!(fn_try_enum, Some(TryEnum::Result)) {
+        postfix_snippet("tryintoq", "expr.try_into()?", &format!("{receiver_text}.try_into()?"))
+            .add_to(acc, ctx.db);
+    }
+    if impls_trait(ctx, receiver_ty, ctx.famous_defs().core_future_Future()) {
+        postfix_snippet(
+            "awaitunwrap",
+            "expr.await.unwrap()",
+            &format!("{receiver_text}.await.unwrap()"),
+        )
+        .add_to(acc, ctx.db);
+        let output_try_enum =
+            future_output_ty(ctx, receiver_ty).and_then(|it| TryEnum::from_ty(&ctx.sema, &it));
+        let compatible = matches!(
+            (output_try_enum, fn_try_enum),
+            (Some(TryEnum::Result), Some(TryEnum::Result))
+                | (Some(TryEnum::Option), Some(TryEnum::Option))
+        );
+        if compatible {
+            postfix_snippet("awaitq", "expr.await?", &format!("{receiver_text}.await?"))
+                .add_to(acc, ctx.db);
+        }
+    }
 
     let mut unsafe_should_be_wrapped = true;
     if dot_receiver.syntax().kind() == BLOCK_EXPR {
@@ -181,6 +529,10 @@ pub(crate) fn complete_postfix(
         add_custom_postfix_completions(acc, ctx, &postfix_snippet, &receiver_text);
     }
 
+    if is_slice_like(ctx, receiver_ty) {
+        add_par_iter_completion(acc, ctx, &postfix_snippet, &receiver_text);
+    }
+
     match try_enum {
         Some(try_enum) => match try_enum {
             TryEnum::Result => {
@@ -212,10 +564,38 @@ pub(crate) fn complete_postfix(
         }
     }
 
+    postfix_snippet(
+        "ifmatch",
+        "if let pat = expr {}",
+        &format!("if let $1 = {receiver_text} {{\n    $0\n}}"),
+    )
+    .add_to(acc, ctx.db);
+
     postfix_snippet("box", "Box::new(expr)", &format!("Box::new({receiver_text})"))
         .add_to(acc, ctx.db);
+    if receiver_ty.is_closure() || has_trait_impls(ctx, receiver_ty) {
+        postfix_snippet(
+            "boxdyn",
+            "Box::new(expr) as Box<dyn Trait>",
+            &format!("Box::new({receiver_text}) as Box<dyn $0>"),
+        )
+        .add_to(acc, ctx.db);
+    }
+    if impls_trait(ctx, receiver_ty, ctx.famous_defs().core_future_Future()) {
+        postfix_snippet("boxpin", "Box::pin(expr)", &format!("Box::pin({receiver_text})"))
+            .add_to(acc, ctx.db);
+    }
     postfix_snippet("dbg", "dbg!(expr)", &format!("dbg!({receiver_text})")).add_to(acc, ctx.db); // fixme
     postfix_snippet("dbgr", "dbg!(&expr)", &format!("dbg!(&{receiver_text})")).add_to(acc, ctx.db);
+    postfix_snippet(
+        "dbgt",
+        "eprintln!(expr: type = value)",
+        &format!(
+            "{{ let v = &{receiver_text}; eprintln!(\"{{}}: {{}} = {{:?}}\", \
+             stringify!({receiver_text}), std::any::type_name_of_val(v), v); }}"
+        ),
+    )
+    .add_to(acc, ctx.db);
     postfix_snippet("call", "function(expr)", &format!("${{1}}({receiver_text})"))
         .add_to(acc, ctx.db);
 
@@ -257,6 +637,153 @@ pub(crate) fn complete_postfix(
     }
 }
 
+/// Whether the receiver is known to implement the trait. Unknown types are never considered to,
+/// as the trait solver can't rule them out.
+fn impls_trait(ctx: &CompletionContext<'_>, ty: &hir::Type, trait_: Option<hir::Trait>) -> bool {
+    !ty.is_unknown() && trait_.is_some_and(|trait_| ty.impls_trait(ctx.db, trait_, &[]))
+}
+
+/// Whether the receiver is an ADT that implements at least one trait, and so can be turned into
+/// some trait object. Blanket impls are not taken into account.
+fn has_trait_impls(ctx: &CompletionContext<'_>, ty: &hir::Type) -> bool {
+    ty.as_adt().is_some()
+        && hir::Impl::all_for_type(ctx.db, ty.clone())
+            .into_iter()
+            .any(|it| it.trait_(ctx.db).is_some())
+}
+
+/// Whether the receiver is an instance of the ADT, which is looked up in the standard library.
+fn is_adt(ty: &hir::Type, adt: Option<impl Into<hir::Adt>>) -> bool {
+    adt.is_some_and(|adt| ty.as_adt() == Some(adt.into()))
+}
+
+/// The `Item` type of an iterator receiver.
+fn iterator_item_ty(ctx: &CompletionContext<'_>, ty: &hir::Type) -> Option<hir::Type> {
+    let iterator = ctx.famous_defs().core_iter_Iterator()?;
+    let item = iterator.items(ctx.db).into_iter().find_map(|item| match item {
+        hir::AssocItem::TypeAlias(alias) if alias.name(ctx.db) == sym::Item.clone() => Some(alias),
+        _ => None,
+    })?;
+    ty.normalize_trait_assoc_type(ctx.db, &[], item).filter(|it| !it.is_unknown())
+}
+
+/// The `Output` type of a future receiver.
+fn future_output_ty(ctx: &CompletionContext<'_>, ty: &hir::Type) -> Option<hir::Type> {
+    let future = ctx.famous_defs().core_future_Future()?;
+    let output = future.items(ctx.db).into_iter().find_map(|item| match item {
+        hir::AssocItem::TypeAlias(alias) if alias.name(ctx.db) == sym::Output.clone() => {
+            Some(alias)
+        }
+        _ => None,
+    })?;
+    ty.normalize_trait_assoc_type(ctx.db, &[], output).filter(|it| !it.is_unknown())
+}
+
+/// Whether the receiver is, or dereferences to, a slice or an array.
+fn is_slice_like(ctx: &CompletionContext<'_>, ty: &hir::Type) -> bool {
+    ty.autoderef(ctx.db).any(|it| it.is_slice() || it.is_array())
+}
+
+/// The element type of the receiver if it is, or dereferences to, a slice or an array.
+fn slice_element_ty(ctx: &CompletionContext<'_>, ty: &hir::Type) -> Option<hir::Type> {
+    ty.autoderef(ctx.db)
+        .find_map(|it| it.as_slice().or_else(|| it.as_array(ctx.db).map(|(element, _)| element)))
+}
+
+/// Whether the receiver is a `Box<[T]>`.
+fn is_boxed_slice(ctx: &CompletionContext<'_>, ty: &hir::Type) -> bool {
+    is_adt(ty, ctx.famous_defs().alloc_boxed_Box())
+        && ty.type_arguments().next().is_some_and(|it| it.is_slice())
+}
+
+/// Whether the receiver is a `Vec`, `VecDeque` or `String` that can be drained in place, either
+/// through a mutable reference or as a place expression.
+fn is_drainable(ctx: &CompletionContext<'_>, receiver: &ast::Expr, ty: &hir::Type) -> bool {
+    let is_place = if ty.is_reference() {
+        ty.is_mutable_reference()
+    } else {
+        match receiver {
+            ast::Expr::PathExpr(_) | ast::Expr::FieldExpr(_) | ast::Expr::IndexExpr(_) => true,
+            ast::Expr::PrefixExpr(it) => it.op_kind() == Some(ast::UnaryOp::Deref),
+            _ => false,
+        }
+    };
+    let ty = ty.strip_references();
+    let famous_defs = ctx.famous_defs();
+    is_place
+        && (is_adt(&ty, famous_defs.alloc_vec_Vec())
+            || is_adt(&ty, famous_defs.alloc_collections_VecDeque())
+            || is_adt(&ty, famous_defs.alloc_string_String()))
+}
+
+/// Whether the receiver is a `HashMap` or a `BTreeMap`, owned or behind a mutable reference since
+/// `entry` takes `&mut self`.
+fn is_map(ctx: &CompletionContext<'_>, ty: &hir::Type) -> bool {
+    if ty.is_reference() && !ty.is_mutable_reference() {
+        return false;
+    }
+    let ty = ty.strip_reference();
+    let famous_defs = ctx.famous_defs();
+    is_adt(&ty, famous_defs.std_collections_HashMap())
+        || is_adt(&ty, famous_defs.alloc_collections_BTreeMap())
+}
+
+/// Whether the receiver is a map `Entry`, as returned by `HashMap::entry` or `BTreeMap::entry`.
+fn is_entry(ctx: &CompletionContext<'_>, ty: &hir::Type) -> bool {
+    let famous_defs = ctx.famous_defs();
+    is_adt(ty, famous_defs.std_collections_hash_map_Entry())
+        || is_adt(ty, famous_defs.alloc_collections_btree_map_Entry())
+}
+
+/// Whether the receiver is in the body of a function that returns a `Result` or an `Option`, so
+/// that `?` can be applied to it, and which of the two. Closures and blocks with their own `?`
+/// scope are not looked into, and the output type is used for `async` functions.
+fn enclosing_fn_try_enum(ctx: &CompletionContext<'_>, receiver: &ast::Expr) -> Option<TryEnum> {
+    let fn_ = receiver
+        .syntax()
+        .ancestors()
+        .take_while(|it| match ast::Expr::cast(it.clone()) {
+            Some(ast::Expr::ClosureExpr(_)) => false,
+            Some(ast::Expr::BlockExpr(block)) => {
+                block.async_token().is_none() && block.try_token().is_none()
+            }
+            _ => true,
+        })
+        .find_map(ast::Fn::cast);
+    let fn_ = ctx.sema.to_def(&fn_?)?;
+    let ret_type = fn_.async_ret_type(ctx.db).unwrap_or_else(|| fn_.ret_type(ctx.db));
+    TryEnum::from_ty(&ctx.sema, &ret_type)
+}
+
+/// Whether the receiver is a method call, or is itself followed by one once the postfix
+/// completion is applied.
+fn is_in_method_chain(receiver: &ast::Expr) -> bool {
+    matches!(receiver, ast::Expr::MethodCallExpr(_))
+        || receiver
+            .syntax()
+            .parent()
+            .and_then(|it| it.parent())
+            .and_then(ast::MethodCallExpr::cast)
+            .is_some()
+}
+
+/// Whether the receiver is a numeric literal without a suffix, such as `42` or `2.5`.
+fn is_unsuffixed_number(receiver: &ast::Expr) -> bool {
+    let ast::Expr::Literal(literal) = receiver else { return false };
+    match literal.kind() {
+        ast::LiteralKind::IntNumber(it) => it.suffix().is_none(),
+        ast::LiteralKind::FloatNumber(it) => it.suffix().is_none(),
+        _ => false,
+    }
+}
+
+/// Whether the receiver is a `str` or a `String`, possibly behind references.
+fn is_string(ctx: &CompletionContext<'_>, ty: &hir::Type) -> bool {
+    let ty = ty.strip_references();
+    ty.as_builtin().is_some_and(|it| it.is_str())
+        || is_adt(&ty, ctx.famous_defs().alloc_string_String())
+}
+
 fn get_receiver_text(receiver: &ast::Expr, receiver_is_ambiguous_float_literal: bool) -> String {
     let mut text = if receiver_is_ambiguous_float_literal {
         let text = receiver.syntax().text();
@@ -379,6 +906,35 @@ fn add_custom_postfix_completions(
     None
 }
 
+/// Adds `expr.par_iter()`, glob importing `rayon::prelude` as rayon asks for unless its traits are
+/// in scope already. Not offered if rayon isn't a dependency.
+fn add_par_iter_completion(
+    acc: &mut Completions,
+    ctx: &CompletionContext<'_>,
+    postfix_snippet: impl Fn(&str, &str, &str) -> Builder,
+    receiver_text: &str,
+) -> Option<()> {
+    let trait_path = make::path_from_text("rayon::prelude::IntoParallelRefIterator");
+    let trait_ = match ctx.scope.speculative_resolve(&trait_path)? {
+        hir::PathResolution::Def(hir::ModuleDef::Trait(it)) => it,
+        _ => return None,
+    };
+    let mut builder =
+        postfix_snippet("pariter", "expr.par_iter()", &format!("{receiver_text}.par_iter()"));
+    if !ctx.traits_in_scope().contains(&trait_.into()) {
+        let scope =
+            ImportScope::find_insert_use_container(&ctx.original_token.parent()?, &ctx.sema)?;
+        let new_scope = scope.clone_for_update();
+        insert_use_glob(&new_scope, trait_path.qualifier()?, &ctx.config.insert_use);
+        let mut import_edit = TextEdit::builder();
+        algo::diff(scope.as_syntax_node(), new_scope.as_syntax_node())
+            .into_text_edit(&mut import_edit);
+        builder.union_edit(import_edit.finish());
+    }
+    builder.add_to(acc, ctx.db);
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use expect_test::{expect, Expect};
@@ -388,6 +944,72 @@ mod tests {
         CompletionConfig, Snippet,
     };
 
+    /// The `std` and `alloc` types the snippets look for, with `String`, `Vec` and `Box` in the
+    /// prelude, and an iterator `It` and a `Bag` that only converts into one.
+    const STD_FIXTURE: &str = r#"
+//- /std.rs crate:std deps:alloc
+pub mod prelude {
+    pub mod rust_2021 {
+        pub use alloc::{boxed::Box, string::String, vec::Vec};
+        pub use core::prelude::rust_2021::*;
+    }
+}
+pub mod collections {
+    pub use self::hash_map::HashMap;
+    pub mod hash_map {
+        pub struct HashMap<K, V>(K, V);
+        pub enum Entry<'a, K, V> { Occupied(&'a mut V), Vacant(K) }
+        impl<K, V> HashMap<K, V> {
+            pub fn entry(&mut self, key: K) -> Entry<'_, K, V> { loop {} }
+        }
+    }
+}
+//- /alloc.rs crate:alloc
+pub mod boxed {
+    pub struct Box<T: ?Sized>(*const T);
+}
+pub mod string {
+    pub struct String;
+}
+pub mod vec {
+    pub struct Vec<T>(T);
+    impl<T> core::ops::Deref for Vec<T> {
+        type Target = [T];
+        fn deref(&self) -> &[T] { loop {} }
+    }
+}
+//- /iters.rs crate:iters
+pub struct It<T = u8>(T);
+impl<T> Iterator for It<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<T> { None }
+}
+impl<T> Clone for It<T> {
+    fn clone(&self) -> Self { loop {} }
+}
+pub struct Bag;
+impl IntoIterator for Bag {
+    type Item = u8;
+    type IntoIter = It;
+    fn into_iter(self) -> It { loop {} }
+}
+"#;
+
+    /// Makes `ra_fixture` the `main` crate of [`STD_FIXTURE`].
+    fn with_std(ra_fixture: &str) -> String {
+        format!(
+            "//- minicore: builtin_impls, clone, deref, iterator, ord, slice, sum\n\
+             //- /main.rs crate:main deps:std,alloc,iters\n{}{}",
+            ra_fixture.trim_start(),
+            STD_FIXTURE.trim_start(),
+        )
+    }
+
+    #[track_caller]
+    fn check_edit_std(what: &str, ra_fixture_before: &str, ra_fixture_after: &str) {
+        check_edit(what, &with_std(ra_fixture_before), ra_fixture_after)
+    }
+
     fn check(ra_fixture: &str, expect: Expect) {
         let actual = completion_list(ra_fixture);
         expect.assert_eq(&actual)
@@ -403,21 +1025,24 @@ fn main() {
 }
 "#,
             expect![[r#"
-                sn box    Box::new(expr)
-                sn call   function(expr)
-                sn dbg    dbg!(expr)
-                sn dbgr   dbg!(&expr)
-                sn deref  *expr
-                sn if     if expr {}
-                sn let    let
-                sn letm   let mut
-                sn match  match expr {}
-                sn not    !expr
-                sn ref    &expr
-                sn refm   &mut expr
-                sn return return expr
-                sn unsafe unsafe {}
-                sn while  while expr {}
+                sn box      Box::new(expr)
+                sn call     function(expr)
+                sn dbg      dbg!(expr)
+                sn dbgr     dbg!(&expr)
+                sn dbgt     eprintln!(expr: type = value)
+                sn deref    *expr
+                sn if       if expr {}
+                sn ifmatch  if let pat = expr {}
+                sn let      let
+                sn letm     let mut
+                sn match    match expr {}
+                sn not      !expr
+                sn ref      &expr
+                sn refm     &mut expr
+                sn return   return expr
+                sn tryintou expr.try_into().unwrap()
+                sn unsafe   unsafe {}
+                sn while    while expr {}
             "#]],
         );
     }
@@ -436,19 +1061,22 @@ fn main() {
 }
 "#,
             expect![[r#"
-                sn box    Box::new(expr)
-                sn call   function(expr)
-                sn dbg    dbg!(expr)
-                sn dbgr   dbg!(&expr)
-                sn deref  *expr
-                sn if     if expr {}
-                sn match  match expr {}
-                sn not    !expr
-                sn ref    &expr
-                sn refm   &mut expr
-                sn return return expr
-                sn unsafe unsafe {}
-                sn while  while expr {}
+                sn box      Box::new(expr)
+                sn call     function(expr)
+                sn dbg      dbg!(expr)
+                sn dbgr     dbg!(&expr)
+                sn dbgt     eprintln!(expr: type = value)
+                sn deref    *expr
+                sn if       if expr {}
+                sn ifmatch  if let pat = expr {}
+                sn match    match expr {}
+                sn not      !expr
+                sn ref      &expr
+                sn refm     &mut expr
+                sn return   return expr
+                sn tryintou expr.try_into().unwrap()
+                sn unsafe   unsafe {}
+                sn while    while expr {}
             "#]],
         );
     }
@@ -463,18 +1091,35 @@ fn main() {
 }
 "#,
             expect![[r#"
-                sn box    Box::new(expr)
-                sn call   function(expr)
-                sn dbg    dbg!(expr)
-                sn dbgr   dbg!(&expr)
-                sn deref  *expr
-                sn let    let
-                sn letm   let mut
-                sn match  match expr {}
-                sn ref    &expr
-                sn refm   &mut expr
-                sn return return expr
-                sn unsafe unsafe {}
+                sn bin         format!("{:b}", expr)
+                sn box         Box::new(expr)
+                sn call        function(expr)
+                sn checkedadd  expr.checked_add(rhs)
+                sn checkedmul  expr.checked_mul(rhs)
+                sn checkedsub  expr.checked_sub(rhs)
+                sn dbg         dbg!(expr)
+                sn dbgr        dbg!(&expr)
+                sn dbgt        eprintln!(expr: type = value)
+                sn deref       *expr
+                sn hex         format!("{:x}", expr)
+                sn ifmatch     if let pat = expr {}
+                sn let         let
+                sn letm        let mut
+                sn match       match expr {}
+                sn max         expr.max(other)
+                sn min         expr.min(other)
+                sn oct         format!("{:o}", expr)
+                sn ref         &expr
+                sn refm        &mut expr
+                sn return      return expr
+                sn satadd      expr.saturating_add(rhs)
+                sn satmul      expr.saturating_mul(rhs)
+                sn satsub      expr.saturating_sub(rhs)
+                sn tryintou    expr.try_into().unwrap()
+                sn unsafe      unsafe {}
+                sn wrappingadd expr.wrapping_add(rhs)
+                sn wrappingmul expr.wrapping_mul(rhs)
+                sn wrappingsub expr.wrapping_sub(rhs)
             "#]],
         )
     }
@@ -489,21 +1134,24 @@ fn main() {
 }
 "#,
             expect![[r#"
-                sn box    Box::new(expr)
-                sn call   function(expr)
-                sn dbg    dbg!(expr)
-                sn dbgr   dbg!(&expr)
-                sn deref  *expr
-                sn if     if expr {}
-                sn let    let
-                sn letm   let mut
-                sn match  match expr {}
-                sn not    !expr
-                sn ref    &expr
-                sn refm   &mut expr
-                sn return return expr
-                sn unsafe unsafe {}
-                sn while  while expr {}
+                sn box      Box::new(expr)
+                sn call     function(expr)
+                sn dbg      dbg!(expr)
+                sn dbgr     dbg!(&expr)
+                sn dbgt     eprintln!(expr: type = value)
+                sn deref    *expr
+                sn if       if expr {}
+                sn ifmatch  if let pat = expr {}
+                sn let      let
+                sn letm     let mut
+                sn match    match expr {}
+                sn not      !expr
+                sn ref      &expr
+                sn refm     &mut expr
+                sn return   return expr
+                sn tryintou expr.try_into().unwrap()
+                sn unsafe   unsafe {}
+                sn while    while expr {}
             "#]],
         );
     }
@@ -531,9 +1179,26 @@ fn main() {
     }
 
     #[test]
-    fn option_letelse() {
+    fn if_match_any_pattern() {
         check_edit(
-            "lete",
+            "ifmatch",
+            r#"
+struct Point { x: i32, y: i32 }
+fn main(p: Point) {
+    p.$0
+}
+"#,
+            r#"
+struct Point { x: i32, y: i32 }
+fn main(p: Point) {
+    if let $1 = p {
+    $0
+}
+}
+"#,
+        );
+        check_edit(
+            "ifmatch",
             r#"
 //- minicore: option
 fn main() {
@@ -544,23 +1209,45 @@ fn main() {
             r#"
 fn main() {
     let bar = Some(true);
-    let Some($1) = bar else {
-    $2
-};
-$0
+    if let $1 = bar {
+    $0
+}
 }
 "#,
         );
     }
 
     #[test]
-    fn result_match() {
+    fn option_letelse() {
         check_edit(
-            "match",
+            "lete",
             r#"
-//- minicore: result
+//- minicore: option
 fn main() {
-    let bar = Ok(true);
+    let bar = Some(true);
+    bar.$0
+}
+"#,
+            r#"
+fn main() {
+    let bar = Some(true);
+    let Some($1) = bar else {
+    $2
+};
+$0
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn result_match() {
+        check_edit(
+            "match",
+            r#"
+//- minicore: result
+fn main() {
+    let bar = Ok(true);
     bar.$0
 }
 "#,
@@ -577,8 +1264,1660 @@ fn main() {
     }
 
     #[test]
-    fn postfix_completion_works_for_ambiguous_float_literal() {
-        check_edit("refm", r#"fn main() { 42.$0 }"#, r#"fn main() { &mut 42 }"#)
+    fn postfix_completion_for_slices() {
+        check_edit_std(
+            "windows",
+            r#"
+fn main(v: Vec<u8>) {
+    v.$0
+}
+"#,
+            r#"
+fn main(v: Vec<u8>) {
+    v.windows($0)
+}
+"#,
+        );
+        check_edit_std(
+            "chunks",
+            r#"
+fn main(v: Vec<u8>) {
+    v.$0
+}
+"#,
+            r#"
+fn main(v: Vec<u8>) {
+    v.chunks($0)
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn postfix_completion_for_slice_splits() {
+        check_edit(
+            "splitfirst",
+            r#"
+//- minicore: slice
+fn main(items: &[u8]) {
+    items.$0
+}
+"#,
+            r#"
+fn main(items: &[u8]) {
+    items.split_first()
+}
+"#,
+        );
+        check_edit(
+            "splitlast",
+            r#"
+//- minicore: slice
+fn main(items: &[u8]) {
+    items.$0
+}
+"#,
+            r#"
+fn main(items: &[u8]) {
+    items.split_last()
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn result_to_option() {
+        check_edit(
+            "toption",
+            r#"
+//- minicore: result
+fn main() {
+    let bar = Ok(true);
+    bar.$0
+}
+"#,
+            r#"
+fn main() {
+    let bar = Ok(true);
+    bar.ok()
+}
+"#,
+        );
+        check_edit(
+            "etoption",
+            r#"
+//- minicore: result
+fn main() {
+    let bar = Ok(true);
+    bar.$0
+}
+"#,
+            r#"
+fn main() {
+    let bar = Ok(true);
+    bar.err()
+}
+"#,
+        );
+
+        // Plain values only get the wrapping snippets.
+        assert!(!completion_list(r#"fn main() { 42.$0 }"#).contains("toption"));
+        let config = CompletionConfig {
+            snippets: vec![Snippet::new(
+                &[],
+                &["ok".into()],
+                &["Ok(${receiver})".into()],
+                "",
+                &[],
+                crate::SnippetScope::Expr,
+            )
+            .unwrap()],
+            ..TEST_CONFIG
+        };
+        check_edit_with_config(config, "ok", r#"fn main() { 42.$0 }"#, r#"fn main() { Ok(42) }"#);
+    }
+
+    #[test]
+    fn option_and_result_variant_checks() {
+        check_edit(
+            "issome",
+            r#"
+//- minicore: option
+fn main() {
+    let bar = Some(true);
+    bar.$0
+}
+"#,
+            r#"
+fn main() {
+    let bar = Some(true);
+    bar.is_some()
+}
+"#,
+        );
+        check_edit(
+            "isnone",
+            r#"
+//- minicore: option
+fn main() {
+    let bar = Some(true);
+    bar.$0
+}
+"#,
+            r#"
+fn main() {
+    let bar = Some(true);
+    bar.is_none()
+}
+"#,
+        );
+        check_edit(
+            "isok",
+            r#"
+//- minicore: result
+fn main() {
+    let bar = Ok(true);
+    bar.$0
+}
+"#,
+            r#"
+fn main() {
+    let bar = Ok(true);
+    bar.is_ok()
+}
+"#,
+        );
+        check_edit(
+            "iserr",
+            r#"
+//- minicore: result
+fn main() {
+    let bar = Ok(true);
+    bar.$0
+}
+"#,
+            r#"
+fn main() {
+    let bar = Ok(true);
+    bar.is_err()
+}
+"#,
+        );
+
+        let option = completion_list(
+            r#"
+//- minicore: option
+fn main() {
+    let bar = Some(true);
+    bar.$0
+}
+"#,
+        );
+        assert!(!option.contains("isok") && !option.contains("iserr"));
+        let result = completion_list(
+            r#"
+//- minicore: result
+fn main() {
+    let bar = Ok(true);
+    bar.$0
+}
+"#,
+        );
+        assert!(!result.contains("issome") && !result.contains("isnone"));
+    }
+
+    #[test]
+    fn postfix_completion_for_to_vec() {
+        check_edit(
+            "tovec",
+            r#"
+//- minicore: slice, clone, builtin_impls
+fn main(items: &[u8]) {
+    items.$0
+}
+"#,
+            r#"
+fn main(items: &[u8]) {
+    items.to_vec()
+}
+"#,
+        );
+        check_edit(
+            "tovec",
+            r#"
+//- minicore: slice, clone, builtin_impls
+fn main() {
+    [1u8, 2, 3].$0
+}
+"#,
+            r#"
+fn main() {
+    [1u8, 2, 3].to_vec()
+}
+"#,
+        );
+        check_edit_std(
+            "intovec",
+            r#"
+fn main(items: Box<[u8]>) {
+    items.$0
+}
+"#,
+            r#"
+fn main(items: Box<[u8]>) {
+    items.into_vec()
+}
+"#,
+        );
+
+        let not_clone = completion_list(
+            r#"
+//- minicore: slice, clone
+struct NotClone;
+fn main(items: &[NotClone]) {
+    items.$0
+}
+"#,
+        );
+        assert!(!not_clone.contains("tovec"));
+        assert!(!not_clone.contains("intovec"));
+
+        let not_box = completion_list(
+            r#"
+struct Box<T: ?Sized>(*const T);
+fn main(items: Box<[u8]>) {
+    items.$0
+}
+"#,
+        );
+        assert!(!not_box.contains("intovec"));
+    }
+
+    #[test]
+    fn dbg_in_method_chain() {
+        check_edit(
+            "dbgc",
+            r#"
+struct S;
+impl S {
+    fn foo(&self) -> S { S }
+    fn bar(&self) {}
+}
+fn main(x: S) {
+    x.foo().dbgc$0.bar();
+}
+"#,
+            r#"
+struct S;
+impl S {
+    fn foo(&self) -> S { S }
+    fn bar(&self) {}
+}
+fn main(x: S) {
+    dbg!(x.foo()).bar();
+}
+"#,
+        );
+        check_edit(
+            "dbgc",
+            r#"
+struct S;
+impl S {
+    fn foo(&self) -> &S { self }
+}
+fn main(x: S) {
+    let _ = &x.foo().dbgc$0;
+}
+"#,
+            r#"
+struct S;
+impl S {
+    fn foo(&self) -> &S { self }
+}
+fn main(x: S) {
+    let _ = &dbg!(x.foo());
+}
+"#,
+        );
+        check_edit(
+            "dbgc",
+            r#"
+struct S;
+impl S {
+    fn bar(&self) {}
+}
+fn main(x: S) {
+    x.dbgc$0.bar();
+}
+"#,
+            r#"
+struct S;
+impl S {
+    fn bar(&self) {}
+}
+fn main(x: S) {
+    dbg!(x).bar();
+}
+"#,
+        );
+
+        let list = completion_list(
+            r#"
+struct S;
+fn main(x: S) {
+    x.$0
+}
+"#,
+        );
+        assert!(!list.contains("sn dbgc"));
+    }
+
+    #[test]
+    fn postfix_completion_for_strings() {
+        check_edit_std(
+            "chars",
+            r#"
+fn main(s: String) {
+    s.$0
+}
+"#,
+            r#"
+fn main(s: String) {
+    s.chars()
+}
+"#,
+        );
+        check_edit_std(
+            "bytes",
+            r#"
+fn main(s: String) {
+    s.$0
+}
+"#,
+            r#"
+fn main(s: String) {
+    s.bytes()
+}
+"#,
+        );
+        check_edit_std(
+            "lines",
+            r#"
+fn main(s: String) {
+    s.$0
+}
+"#,
+            r#"
+fn main(s: String) {
+    s.lines()
+}
+"#,
+        );
+        check_edit(
+            "chars",
+            r#"
+fn main(s: &str) {
+    s.$0
+}
+"#,
+            r#"
+fn main(s: &str) {
+    s.chars()
+}
+"#,
+        );
+
+        let list = completion_list(
+            r#"
+fn main() {
+    42.$0
+}
+"#,
+        );
+        assert!(!list.contains("sn chars") && !list.contains("sn bytes"));
+        assert!(!list.contains("sn lines"));
+
+        let list = completion_list(
+            r#"
+struct String;
+fn main(s: String) {
+    s.$0
+}
+"#,
+        );
+        assert!(!list.contains("sn chars"));
+    }
+
+    #[test]
+    fn postfix_completion_for_char_indices() {
+        check_edit_std(
+            "charindices",
+            r#"
+fn main(s: String) {
+    s.$0
+}
+"#,
+            r#"
+fn main(s: String) {
+    for (${1:i}, ${2:c}) in s.char_indices() {
+    $0
+}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn postfix_completion_for_string_transforms() {
+        check_edit_std(
+            "trim",
+            r#"
+fn main(s: String) {
+    s.$0
+}
+"#,
+            r#"
+fn main(s: String) {
+    s.trim()
+}
+"#,
+        );
+        check_edit_std(
+            "lower",
+            r#"
+fn main(s: String) {
+    s.$0
+}
+"#,
+            r#"
+fn main(s: String) {
+    s.to_lowercase()
+}
+"#,
+        );
+        check_edit_std(
+            "upper",
+            r#"
+fn main(s: String) {
+    s.$0
+}
+"#,
+            r#"
+fn main(s: String) {
+    s.to_uppercase()
+}
+"#,
+        );
+
+        let list = completion_list(
+            r#"
+struct Vec<T>(T);
+fn main(v: Vec<u8>) {
+    v.$0
+}
+"#,
+        );
+        assert!(!list.contains("sn trim") && !list.contains("sn lower"));
+        assert!(!list.contains("sn upper"));
+    }
+
+    #[test]
+    fn postfix_completion_for_string_split() {
+        check_edit_std(
+            "split",
+            r#"
+fn main(s: String) {
+    s.$0
+}
+"#,
+            r#"
+fn main(s: String) {
+    s.split($0)
+}
+"#,
+        );
+        check_edit_std(
+            "splitws",
+            r#"
+fn main(s: String) {
+    s.$0
+}
+"#,
+            r#"
+fn main(s: String) {
+    s.split_whitespace()
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn postfix_completion_for_string_replace() {
+        check_edit_std(
+            "replace",
+            r#"
+fn main(s: String) {
+    s.$0
+}
+"#,
+            r#"
+fn main(s: String) {
+    s.replace($1, $0)
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn postfix_completion_for_reversed() {
+        check_edit_std(
+            "reversed",
+            r#"
+fn main(items: Vec<u8>) {
+    items.$0
+}
+"#,
+            r#"
+fn main(items: Vec<u8>) {
+    items.iter().rev().collect::<Vec<_>>()
+}
+"#,
+        );
+
+        let list = completion_list(
+            r#"
+struct HashMap<K, V>(K, V);
+fn main(items: HashMap<u8, u8>) {
+    items.$0
+}
+"#,
+        );
+        assert!(!list.contains("sn reversed"));
+    }
+
+    #[test]
+    fn postfix_completion_for_map_collect() {
+        check_edit_std(
+            "mapcollect",
+            r#"
+fn main(items: Vec<u8>) {
+    items.$0
+}
+"#,
+            r#"
+fn main(items: Vec<u8>) {
+    items.iter().map(|$1| $0).collect::<Vec<_>>()
+}
+"#,
+        );
+        check_edit_std(
+            "mapcollect",
+            r#"
+fn main(bag: iters::Bag) {
+    bag.$0
+}
+"#,
+            r#"
+fn main(bag: iters::Bag) {
+    bag.into_iter().map(|$1| $0).collect::<Vec<_>>()
+}
+"#,
+        );
+
+        let list = completion_list(r#"fn main(x: u32) { x.$0 }"#);
+        assert!(!list.contains("sn mapcollect"));
+    }
+
+    #[test]
+    fn postfix_completion_for_aggregation() {
+        check_edit_std(
+            "join",
+            r#"
+fn main(items: Vec<String>) {
+    items.$0
+}
+"#,
+            r#"
+fn main(items: Vec<String>) {
+    items.join($0)
+}
+"#,
+        );
+        check_edit_std(
+            "concat",
+            r#"
+fn main(items: Vec<String>) {
+    items.$0
+}
+"#,
+            r#"
+fn main(items: Vec<String>) {
+    items.concat()
+}
+"#,
+        );
+        check_edit(
+            "concat",
+            r#"
+fn main(items: &[[u8; 2]]) {
+    items.$0
+}
+"#,
+            r#"
+fn main(items: &[[u8; 2]]) {
+    items.concat()
+}
+"#,
+        );
+        check_edit(
+            "repeat",
+            r#"
+fn main(items: &str) {
+    items.$0
+}
+"#,
+            r#"
+fn main(items: &str) {
+    items.repeat($0)
+}
+"#,
+        );
+
+        let list = completion_list(
+            r#"
+fn main(items: &[u8]) {
+    items.$0
+}
+"#,
+        );
+        assert!(!list.contains("sn join") && !list.contains("sn concat"));
+        assert!(!list.contains("sn repeat"));
+    }
+
+    #[test]
+    fn postfix_completion_for_searches() {
+        check_edit_std(
+            "contains",
+            r#"
+fn main(x: String) {
+    x.$0
+}
+"#,
+            r#"
+fn main(x: String) {
+    x.contains($0)
+}
+"#,
+        );
+        check_edit_std(
+            "startswith",
+            r#"
+fn main(x: String) {
+    x.$0
+}
+"#,
+            r#"
+fn main(x: String) {
+    x.starts_with($0)
+}
+"#,
+        );
+        check_edit_std(
+            "endswith",
+            r#"
+fn main(x: String) {
+    x.$0
+}
+"#,
+            r#"
+fn main(x: String) {
+    x.ends_with($0)
+}
+"#,
+        );
+        check_edit(
+            "contains",
+            r#"
+fn main(x: &[u8]) {
+    x.$0
+}
+"#,
+            r#"
+fn main(x: &[u8]) {
+    x.contains($0)
+}
+"#,
+        );
+        check_edit(
+            "startswith",
+            r#"
+fn main(x: &[u8]) {
+    x.$0
+}
+"#,
+            r#"
+fn main(x: &[u8]) {
+    x.starts_with($0)
+}
+"#,
+        );
+
+        let list = completion_list(
+            r#"
+fn main() {
+    42.$0
+}
+"#,
+        );
+        assert!(!list.contains("sn contains") && !list.contains("sn startswith"));
+    }
+
+    #[test]
+    fn postfix_completion_for_drain() {
+        check_edit_std(
+            "drain",
+            r#"
+fn main(mut v: Vec<u8>) {
+    v.$0
+}
+"#,
+            r#"
+fn main(mut v: Vec<u8>) {
+    v.drain($0)
+}
+"#,
+        );
+        check_edit_std(
+            "drain",
+            r#"
+fn main(v: &mut Vec<u8>) {
+    v.$0
+}
+"#,
+            r#"
+fn main(v: &mut Vec<u8>) {
+    v.drain($0)
+}
+"#,
+        );
+
+        assert!(!completion_list(&with_std(
+            r#"
+fn main(v: &Vec<u8>) {
+    v.$0
+}
+"#
+        ))
+        .contains("drain"));
+        assert!(!completion_list(&with_std(
+            r#"
+fn make() -> Vec<u8> { loop {} }
+fn main() {
+    make().$0
+}
+"#
+        ))
+        .contains("drain"));
+        assert!(!completion_list(
+            r#"
+struct Vec<T>(T);
+fn main(mut v: Vec<u8>) {
+    v.$0
+}
+"#
+        )
+        .contains("drain"));
+    }
+
+    #[test]
+    fn result_unwrap_err() {
+        check_edit(
+            "unwraperr",
+            r#"
+//- minicore: result
+fn main() {
+    let bar = Err(true);
+    bar.$0
+}
+"#,
+            r#"
+fn main() {
+    let bar = Err(true);
+    bar.unwrap_err()
+}
+"#,
+        );
+        check_edit(
+            "expecterr",
+            r#"
+//- minicore: result
+fn main() {
+    let bar = Err(true);
+    bar.$0
+}
+"#,
+            r#"
+fn main() {
+    let bar = Err(true);
+    bar.expect_err("$0")
+}
+"#,
+        );
+
+        let option = completion_list(
+            r#"
+//- minicore: option
+fn main() {
+    let bar = Some(true);
+    bar.$0
+}
+"#,
+        );
+        assert!(!option.contains("unwraperr") && !option.contains("expecterr"));
+    }
+
+    #[test]
+    fn postfix_completion_for_iterator_search() {
+        check_edit_std(
+            "find",
+            r#"
+fn main(it: iters::It) {
+    it.$0
+}
+"#,
+            r#"
+fn main(it: iters::It) {
+    it.find(|$1| $0)
+}
+"#,
+        );
+        check_edit_std(
+            "position",
+            r#"
+fn main(it: iters::It) {
+    it.$0
+}
+"#,
+            r#"
+fn main(it: iters::It) {
+    it.position(|$1| $0)
+}
+"#,
+        );
+
+        let list = completion_list(
+            r#"
+//- minicore: iterator
+fn main() {
+    42.$0
+}
+"#,
+        );
+        assert!(!list.contains("sn find") && !list.contains("sn position"));
+    }
+
+    #[test]
+    fn postfix_completion_for_iterator_adapters() {
+        check_edit_std(
+            "takewhile",
+            r#"
+fn main(it: iters::It) {
+    it.$0
+}
+"#,
+            r#"
+fn main(it: iters::It) {
+    it.take_while(|$1| $0)
+}
+"#,
+        );
+        check_edit_std(
+            "skipwhile",
+            r#"
+fn main(it: iters::It) {
+    it.$0
+}
+"#,
+            r#"
+fn main(it: iters::It) {
+    it.skip_while(|$1| $0)
+}
+"#,
+        );
+        check_edit_std(
+            "scan",
+            r#"
+fn main(it: iters::It) {
+    it.$0
+}
+"#,
+            r#"
+fn main(it: iters::It) {
+    it.scan(${1:init}, |${2:state}, ${3:x}| $0)
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn postfix_completion_for_iterator_peekable_cycle_step_by() {
+        check_edit_std(
+            "peekable",
+            r#"
+fn main(it: iters::It) {
+    it.$0
+}
+"#,
+            r#"
+fn main(it: iters::It) {
+    it.peekable()
+}
+"#,
+        );
+        check_edit_std(
+            "cycle",
+            r#"
+fn main(it: iters::It) {
+    it.$0
+}
+"#,
+            r#"
+fn main(it: iters::It) {
+    it.cycle()
+}
+"#,
+        );
+        check_edit_std(
+            "stepby",
+            r#"
+fn main(it: iters::It) {
+    it.$0
+}
+"#,
+            r#"
+fn main(it: iters::It) {
+    it.step_by($0)
+}
+"#,
+        );
+
+        let list = completion_list(
+            r#"
+//- minicore: iterator, clone
+struct It;
+impl Iterator for It {
+    type Item = u8;
+    fn next(&mut self) -> Option<u8> { None }
+}
+fn main(it: It) {
+    it.$0
+}
+"#,
+        );
+        assert!(list.contains("sn peekable") && !list.contains("sn cycle"));
+    }
+
+    #[test]
+    fn postfix_completion_for_iterator_reductions() {
+        check_edit_std(
+            "min",
+            r#"
+fn main(it: iters::It<u32>) {
+    it.$0
+}
+"#,
+            r#"
+fn main(it: iters::It<u32>) {
+    it.min()
+}
+"#,
+        );
+        check_edit_std(
+            "max",
+            r#"
+fn main(it: iters::It<u32>) {
+    it.$0
+}
+"#,
+            r#"
+fn main(it: iters::It<u32>) {
+    it.max()
+}
+"#,
+        );
+        check_edit_std(
+            "sum",
+            r#"
+fn main(it: iters::It<u32>) {
+    it.$0
+}
+"#,
+            r#"
+fn main(it: iters::It<u32>) {
+    it.sum::<${1:u32}>()
+}
+"#,
+        );
+        check_edit_std(
+            "product",
+            r#"
+fn main(it: iters::It<u32>) {
+    it.$0
+}
+"#,
+            r#"
+fn main(it: iters::It<u32>) {
+    it.product::<${1:u32}>()
+}
+"#,
+        );
+        check_edit_std(
+            "sum",
+            r#"
+fn main(it: iters::It<u32>) {
+    let total: u32 = it.$0;
+}
+"#,
+            r#"
+fn main(it: iters::It<u32>) {
+    let total: u32 = it.sum();
+}
+"#,
+        );
+
+        let list = completion_list(&with_std(
+            r#"
+struct S;
+fn main(it: iters::It<S>) {
+    it.$0
+}
+"#,
+        ));
+        assert!(!list.contains("sn min") && !list.contains("sn max"));
+        assert!(!list.contains("sn sum") && !list.contains("sn product"));
+    }
+
+    #[test]
+    fn postfix_completion_for_count() {
+        check_edit_std(
+            "count",
+            r#"
+fn main(it: iters::It) {
+    it.$0
+}
+"#,
+            r#"
+fn main(it: iters::It) {
+    it.count()
+}
+"#,
+        );
+        check_edit_std(
+            "count",
+            r#"
+fn main(bag: iters::Bag) {
+    bag.$0
+}
+"#,
+            r#"
+fn main(bag: iters::Bag) {
+    bag.into_iter().count()
+}
+"#,
+        );
+
+        let list = completion_list(
+            r#"
+//- minicore: iterator
+fn main() {
+    42.$0
+}
+"#,
+        );
+        assert!(!list.contains("sn count"));
+    }
+
+    #[test]
+    fn postfix_completion_for_flat_map() {
+        check_edit_std(
+            "flatmap",
+            r#"
+fn main(it: iters::It) {
+    it.$0
+}
+"#,
+            r#"
+fn main(it: iters::It) {
+    it.flat_map(|$1| $0)
+}
+"#,
+        );
+        check_edit_std(
+            "flatmap",
+            r#"
+fn main(bag: iters::Bag) {
+    bag.$0
+}
+"#,
+            r#"
+fn main(bag: iters::Bag) {
+    bag.into_iter().flat_map(|$1| $0)
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn map_entry() {
+        check_edit_std(
+            "entry",
+            r#"
+use std::collections::HashMap;
+fn main(mut map: HashMap<u32, u32>) {
+    map.$0
+}
+"#,
+            r#"
+use std::collections::HashMap;
+fn main(mut map: HashMap<u32, u32>) {
+    map.entry($1).or_insert($0)
+}
+"#,
+        );
+        check_edit_std(
+            "entry",
+            r#"
+use std::collections::HashMap;
+fn main(map: &mut HashMap<u32, u32>) {
+    map.$0
+}
+"#,
+            r#"
+use std::collections::HashMap;
+fn main(map: &mut HashMap<u32, u32>) {
+    map.entry($1).or_insert($0)
+}
+"#,
+        );
+
+        let list = completion_list(&with_std(
+            r#"
+use std::collections::HashMap;
+fn main(map: &HashMap<u32, u32>) {
+    map.$0
+}
+"#,
+        ));
+        assert!(!list.contains("sn entry"));
+
+        let list = completion_list(
+            r#"
+struct HashMap<K, V>(K, V);
+fn main(mut map: HashMap<u32, u32>) {
+    map.$0
+}
+"#,
+        );
+        assert!(!list.contains("sn entry"));
+    }
+
+    #[test]
+    fn map_entry_or_default() {
+        let fixture = r#"
+use std::collections::HashMap;
+fn main(mut map: HashMap<u32, u32>) {
+    map.entry(1).$0
+}
+"#;
+        check_edit_std(
+            "ordefault",
+            fixture,
+            r#"
+use std::collections::HashMap;
+fn main(mut map: HashMap<u32, u32>) {
+    map.entry(1).or_default()
+}
+"#,
+        );
+        check_edit_std(
+            "orinsertwith",
+            fixture,
+            r#"
+use std::collections::HashMap;
+fn main(mut map: HashMap<u32, u32>) {
+    map.entry(1).or_insert_with(|| $0)
+}
+"#,
+        );
+
+        let list = completion_list(
+            r#"
+enum Entry { Occupied, Vacant }
+fn main(entry: Entry) {
+    entry.$0
+}
+"#,
+        );
+        assert!(!list.contains("sn ordefault") && !list.contains("sn orinsertwith"));
+    }
+
+    #[test]
+    fn postfix_completion_for_par_iter() {
+        check_edit(
+            "pariter",
+            r#"
+//- minicore: slice
+//- /main.rs crate:main deps:rayon
+fn main(v: &[u8]) {
+    v.$0
+}
+//- /lib.rs crate:rayon
+pub mod prelude {
+    pub trait IntoParallelRefIterator {
+        fn par_iter(&self);
+    }
+}
+"#,
+            r#"
+use rayon::prelude::*;
+
+fn main(v: &[u8]) {
+    v.par_iter()
+}
+"#,
+        );
+        check_edit(
+            "pariter",
+            r#"
+//- minicore: slice
+//- /main.rs crate:main deps:rayon
+use rayon::prelude::*;
+
+fn main(v: &[u8]) {
+    v.$0
+}
+//- /lib.rs crate:rayon
+pub mod prelude {
+    pub trait IntoParallelRefIterator {
+        fn par_iter(&self);
+    }
+}
+"#,
+            r#"
+use rayon::prelude::*;
+
+fn main(v: &[u8]) {
+    v.par_iter()
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn postfix_completion_works_for_ambiguous_float_literal() {
+        check_edit("refm", r#"fn main() { 42.$0 }"#, r#"fn main() { &mut 42 }"#)
+    }
+
+    #[test]
+    fn postfix_completion_for_radix_formatting() {
+        check_edit(
+            "hex",
+            r#"fn main(x: u32) { x.$0 }"#,
+            r#"fn main(x: u32) { format!("{:x}", x) }"#,
+        );
+        check_edit(
+            "bin",
+            r#"fn main(x: u32) { x.$0 }"#,
+            r#"fn main(x: u32) { format!("{:b}", x) }"#,
+        );
+        check_edit(
+            "oct",
+            r#"fn main(x: u32) { x.$0 }"#,
+            r#"fn main(x: u32) { format!("{:o}", x) }"#,
+        );
+
+        let list = completion_list(r#"fn main(x: f64) { x.$0 }"#);
+        assert!(!list.contains("sn hex") && !list.contains("sn bin") && !list.contains("sn oct"));
+    }
+
+    #[test]
+    fn postfix_completion_for_explicit_overflow_arithmetic() {
+        check_edit(
+            "satadd",
+            r#"fn main(x: u32) { x.$0 }"#,
+            r#"fn main(x: u32) { x.saturating_add($0) }"#,
+        );
+        check_edit(
+            "checkedadd",
+            r#"fn main(x: u32) { x.$0 }"#,
+            r#"fn main(x: u32) { x.checked_add($0) }"#,
+        );
+        check_edit(
+            "wrappingmul",
+            r#"fn main(x: i64) { x.$0 }"#,
+            r#"fn main(x: i64) { x.wrapping_mul($0) }"#,
+        );
+
+        let list = completion_list(r#"fn main(x: f64) { x.$0 }"#);
+        assert!(!list.contains("sn satadd") && !list.contains("sn checkedadd"));
+    }
+
+    #[test]
+    fn postfix_completion_for_default() {
+        check_edit(
+            "default",
+            r#"
+//- minicore: default, derive
+#[derive(Default)]
+struct Config { verbose: bool }
+fn main(config: Config) {
+    config.$0
+}
+"#,
+            r#"
+#[derive(Default)]
+struct Config { verbose: bool }
+fn main(config: Config) {
+    Config::default()
+}
+"#,
+        );
+        check_edit(
+            "default",
+            r#"
+//- minicore: default
+struct Wrapper<T>(T);
+impl<T> Default for Wrapper<T> {
+    fn default() -> Self { loop {} }
+}
+fn main(w: Wrapper<u8>) {
+    w.$0
+}
+"#,
+            r#"
+struct Wrapper<T>(T);
+impl<T> Default for Wrapper<T> {
+    fn default() -> Self { loop {} }
+}
+fn main(w: Wrapper<u8>) {
+    <Wrapper<u8>>::default()
+}
+"#,
+        );
+
+        let list = completion_list(
+            r#"
+//- minicore: default
+struct NoDefault;
+fn main(x: NoDefault) {
+    x.$0
+}
+"#,
+        );
+        assert!(!list.contains("sn default"));
+    }
+
+    #[test]
+    fn postfix_completion_for_numbers() {
+        check_edit("abs", r#"fn main(x: f64) { x.$0 }"#, r#"fn main(x: f64) { x.abs() }"#);
+        check_edit("min", r#"fn main(x: f64) { x.$0 }"#, r#"fn main(x: f64) { x.min($0) }"#);
+        check_edit("max", r#"fn main(x: f64) { x.$0 }"#, r#"fn main(x: f64) { x.max($0) }"#);
+        check_edit("abs", r#"fn main() { 2.5f64.$0 }"#, r#"fn main() { 2.5f64.abs() }"#);
+        check_edit("max", r#"fn main() { 42i32.$0 }"#, r#"fn main() { 42i32.max($0) }"#);
+
+        let list = completion_list(r#"fn main(x: u32) { x.$0 }"#);
+        assert!(list.contains("sn min") && !list.contains("sn abs"));
+        for literal in ["42", "2.5"] {
+            let list = completion_list(&format!("fn main() {{ {literal}.$0 }}"));
+            assert!(!list.contains("sn abs") && !list.contains("sn min"), "{literal}");
+            assert!(!list.contains("sn max"), "{literal}");
+        }
+    }
+
+    #[test]
+    fn postfix_completion_for_dbg_with_type() {
+        check_edit(
+            "dbgt",
+            r#"
+fn main() {
+    let bar = vec![1u8];
+    bar.$0
+}
+"#,
+            r#"
+fn main() {
+    let bar = vec![1u8];
+    { let v = &bar; eprintln!("{}: {} = {:?}", stringify!(bar), std::any::type_name_of_val(v), v); }
+}
+"#,
+        );
+        check_edit(
+            "dbgt",
+            r#"
+struct S;
+impl S {
+    fn next(&mut self) -> u8 { 0 }
+}
+fn main(mut s: S) {
+    s.next().$0
+}
+"#,
+            r#"
+struct S;
+impl S {
+    fn next(&mut self) -> u8 { 0 }
+}
+fn main(mut s: S) {
+    { let v = &s.next(); eprintln!("{}: {} = {:?}", stringify!(s.next()), std::any::type_name_of_val(v), v); }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn postfix_completion_for_box_pin() {
+        check_edit(
+            "boxpin",
+            r#"
+//- minicore: future
+struct Fut;
+impl core::future::Future for Fut {
+    type Output = ();
+    fn poll(
+        self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<()> {
+        loop {}
+    }
+}
+fn main() {
+    Fut.$0
+}
+"#,
+            r#"
+struct Fut;
+impl core::future::Future for Fut {
+    type Output = ();
+    fn poll(
+        self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<()> {
+        loop {}
+    }
+}
+fn main() {
+    Box::pin(Fut)
+}
+"#,
+        );
+
+        let list = completion_list(r#"fn main(x: u32) { x.$0 }"#);
+        assert!(!list.contains("sn boxpin"));
+    }
+
+    #[test]
+    fn postfix_completion_for_box_dyn() {
+        check_edit(
+            "boxdyn",
+            r#"
+trait Shape {}
+struct Circle;
+impl Shape for Circle {}
+fn main() {
+    let shape = Circle.$0;
+}
+"#,
+            r#"
+trait Shape {}
+struct Circle;
+impl Shape for Circle {}
+fn main() {
+    let shape = Box::new(Circle) as Box<dyn $0>;
+}
+"#,
+        );
+
+        check_edit(
+            "boxdyn",
+            r#"
+fn main() {
+    let f = || {};
+    let f = f.$0;
+}
+"#,
+            r#"
+fn main() {
+    let f = || {};
+    let f = Box::new(f) as Box<dyn $0>;
+}
+"#,
+        );
+
+        let list = completion_list(r#"fn main(x: &u32) { x.$0 }"#);
+        assert!(!list.contains("sn boxdyn"));
+        let list = completion_list(r#"fn main(x: u32) { x.$0 }"#);
+        assert!(!list.contains("sn boxdyn"));
+        let list = completion_list(
+            r#"
+struct Circle;
+fn main() {
+    let shape = Circle.$0;
+}
+"#,
+        );
+        assert!(!list.contains("sn boxdyn"));
+    }
+
+    #[test]
+    fn postfix_completion_for_try_into() {
+        check_edit(
+            "tryintou",
+            r#"
+fn main() {
+    let x = 1u64;
+    let y: u8 = x.$0;
+}
+"#,
+            r#"
+fn main() {
+    let x = 1u64;
+    let y: u8 = x.try_into().unwrap();
+}
+"#,
+        );
+        check_edit(
+            "tryintoq",
+            r#"
+//- minicore: result
+struct E;
+fn f(x: u64) -> Result<u8, E> {
+    let y: u8 = x.$0;
+    Ok(y)
+}
+"#,
+            r#"
+struct E;
+fn f(x: u64) -> Result<u8, E> {
+    let y: u8 = x.try_into()?;
+    Ok(y)
+}
+"#,
+        );
+
+        let list = completion_list(r#"fn main(x: u64) { x.$0 }"#);
+        assert!(list.contains("sn tryintou") && !list.contains("sn tryintoq"));
+        let list = completion_list(
+            r#"
+//- minicore: option
+fn f(x: u64) -> Option<u8> {
+    let y: u8 = x.$0;
+    Some(y)
+}
+"#,
+        );
+        assert!(list.contains("sn tryintou") && !list.contains("sn tryintoq"));
+        let list = completion_list(
+            r#"
+//- minicore: option
+fn f(x: u64) -> Option<u8> {
+    let g = || x.$0;
+    None
+}
+"#,
+        );
+        assert!(!list.contains("sn tryintoq"));
+    }
+
+    #[test]
+    fn postfix_completion_for_await() {
+        check_edit(
+            "awaitunwrap",
+            r#"
+//- minicore: future, result
+async fn spawn() -> Result<u32, ()> { Ok(0) }
+async fn f() {
+    let x = spawn().$0;
+}
+"#,
+            r#"
+async fn spawn() -> Result<u32, ()> { Ok(0) }
+async fn f() {
+    let x = spawn().await.unwrap();
+}
+"#,
+        );
+        check_edit(
+            "awaitq",
+            r#"
+//- minicore: future, result
+async fn spawn() -> Result<u32, ()> { Ok(0) }
+async fn f() -> Result<(), ()> {
+    let x = spawn().$0;
+    Ok(())
+}
+"#,
+            r#"
+async fn spawn() -> Result<u32, ()> { Ok(0) }
+async fn f() -> Result<(), ()> {
+    let x = spawn().await?;
+    Ok(())
+}
+"#,
+        );
+
+        let list = completion_list(
+            r#"
+//- minicore: future, option, result
+async fn spawn() -> Result<u32, ()> { Ok(0) }
+async fn f() -> Option<()> {
+    let x = spawn().$0;
+    None
+}
+"#,
+        );
+        assert!(list.contains("sn awaitunwrap") && !list.contains("sn awaitq"));
+        let list = completion_list(r#"fn main(x: u32) { x.$0 }"#);
+        assert!(!list.contains("sn awaitunwrap"));
     }
 
     #[test]
@@ -596,6 +596,21 @@ pub(crate) fn snippet_edit(&mut self, _cap: SnippetCap, edit: TextEdit) -> &mut
         self.is_snippet = true;
         self.text_edit(edit)
     }
+    /// Adds `edit` to an item that already has a text edit, e.g. an import that can't be
+    /// deferred to [`Builder::add_import`].
+    pub(crate) fn union_edit(&mut self, edit: TextEdit) -> &mut Builder {
+        match &mut self.text_edit {
+            Some(text_edit) => {
+                if text_edit.union(edit).is_err() {
+                    never!("completion edits overlap");
+                }
+            }
+            None => {
+                never!("no text edit to add to");
+            }
+        }
+        self
+    }
     pub(crate) fn detail(&mut self, detail: impl Into<String>) -> &mut Builder {
         self.set_detail(Some(detail))
     }
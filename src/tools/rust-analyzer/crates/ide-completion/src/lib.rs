@@ -57,6 +57,7 @@
 //
 // - `expr.if` -> `if expr {}` or `if let ... {}` for `Option` or `Result`
 // - `expr.match` -> `match expr {}`
+// - `expr.ifmatch` -> `if let $1 = expr {}`
 // - `expr.while` -> `while expr {}` or `while let ... {}` for `Option` or `Result`
 // - `expr.ref` -> `&expr`
 // - `expr.refm` -> `&mut expr`
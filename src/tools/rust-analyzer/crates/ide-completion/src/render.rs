@@ -2681,11 +2681,14 @@ fn main() {
                 sn ref []
                 sn refm []
                 sn deref []
+                sn tryintou []
                 sn unsafe []
                 sn match []
+                sn ifmatch []
                 sn box []
                 sn dbg []
                 sn dbgr []
+                sn dbgt []
                 sn call []
                 sn return []
             "#]],
@@ -2711,11 +2714,14 @@ fn main() {
                 sn ref []
                 sn refm []
                 sn deref []
+                sn tryintou []
                 sn unsafe []
                 sn match []
+                sn ifmatch []
                 sn box []
                 sn dbg []
                 sn dbgr []
+                sn dbgt []
                 sn call []
                 sn let []
                 sn letm []
@@ -376,22 +376,25 @@ fn my()        fn()
     check_empty(
         r"fn my() { loop { foo.$0 } }",
         expect![[r#"
-            sn box    Box::new(expr)
-            sn break  break expr
-            sn call   function(expr)
-            sn dbg    dbg!(expr)
-            sn dbgr   dbg!(&expr)
-            sn deref  *expr
-            sn if     if expr {}
-            sn let    let
-            sn letm   let mut
-            sn match  match expr {}
-            sn not    !expr
-            sn ref    &expr
-            sn refm   &mut expr
-            sn return return expr
-            sn unsafe unsafe {}
-            sn while  while expr {}
+            sn box      Box::new(expr)
+            sn break    break expr
+            sn call     function(expr)
+            sn dbg      dbg!(expr)
+            sn dbgr     dbg!(&expr)
+            sn dbgt     eprintln!(expr: type = value)
+            sn deref    *expr
+            sn if       if expr {}
+            sn ifmatch  if let pat = expr {}
+            sn let      let
+            sn letm     let mut
+            sn match    match expr {}
+            sn not      !expr
+            sn ref      &expr
+            sn refm     &mut expr
+            sn return   return expr
+            sn tryintou expr.try_into().unwrap()
+            sn unsafe   unsafe {}
+            sn while    while expr {}
         "#]],
     );
 }
@@ -1170,17 +1173,20 @@ fn main() {
 }
 "#,
         expect![[r#"
-            me foo()  fn(&self)
-            sn box    Box::new(expr)
-            sn call   function(expr)
-            sn dbg    dbg!(expr)
-            sn dbgr   dbg!(&expr)
-            sn deref  *expr
-            sn match  match expr {}
-            sn ref    &expr
-            sn refm   &mut expr
-            sn return return expr
-            sn unsafe unsafe {}
+            me foo()    fn(&self)
+            sn box      Box::new(expr)
+            sn call     function(expr)
+            sn dbg      dbg!(expr)
+            sn dbgr     dbg!(&expr)
+            sn dbgt     eprintln!(expr: type = value)
+            sn deref    *expr
+            sn ifmatch  if let pat = expr {}
+            sn match    match expr {}
+            sn ref      &expr
+            sn refm     &mut expr
+            sn return   return expr
+            sn tryintou expr.try_into().unwrap()
+            sn unsafe   unsafe {}
         "#]],
     );
     check_empty(
@@ -1196,17 +1202,20 @@ fn main() {
 }
 "#,
         expect![[r#"
-            me foo()  fn(&self)
-            sn box    Box::new(expr)
-            sn call   function(expr)
-            sn dbg    dbg!(expr)
-            sn dbgr   dbg!(&expr)
-            sn deref  *expr
-            sn match  match expr {}
-            sn ref    &expr
-            sn refm   &mut expr
-            sn return return expr
-            sn unsafe unsafe {}
+            me foo()    fn(&self)
+            sn box      Box::new(expr)
+            sn call     function(expr)
+            sn dbg      dbg!(expr)
+            sn dbgr     dbg!(&expr)
+            sn dbgt     eprintln!(expr: type = value)
+            sn deref    *expr
+            sn ifmatch  if let pat = expr {}
+            sn match    match expr {}
+            sn ref      &expr
+            sn refm     &mut expr
+            sn return   return expr
+            sn tryintou expr.try_into().unwrap()
+            sn unsafe   unsafe {}
         "#]],
     );
 }
@@ -1226,17 +1235,20 @@ fn main() {
 }
 "#,
         expect![[r#"
-            me foo()  fn(&self)
-            sn box    Box::new(expr)
-            sn call   function(expr)
-            sn dbg    dbg!(expr)
-            sn dbgr   dbg!(&expr)
-            sn deref  *expr
-            sn match  match expr {}
-            sn ref    &expr
-            sn refm   &mut expr
-            sn return return expr
-            sn unsafe unsafe {}
+            me foo()    fn(&self)
+            sn box      Box::new(expr)
+            sn call     function(expr)
+            sn dbg      dbg!(expr)
+            sn dbgr     dbg!(&expr)
+            sn dbgt     eprintln!(expr: type = value)
+            sn deref    *expr
+            sn ifmatch  if let pat = expr {}
+            sn match    match expr {}
+            sn ref      &expr
+            sn refm     &mut expr
+            sn return   return expr
+            sn tryintou expr.try_into().unwrap()
+            sn unsafe   unsafe {}
         "#]],
     );
     check_empty(
@@ -1252,17 +1264,20 @@ fn main() {
 }
 "#,
         expect![[r#"
-            me foo()  fn(&self)
-            sn box    Box::new(expr)
-            sn call   function(expr)
-            sn dbg    dbg!(expr)
-            sn dbgr   dbg!(&expr)
-            sn deref  *expr
-            sn match  match expr {}
-            sn ref    &expr
-            sn refm   &mut expr
-            sn return return expr
-            sn unsafe unsafe {}
+            me foo()    fn(&self)
+            sn box      Box::new(expr)
+            sn call     function(expr)
+            sn dbg      dbg!(expr)
+            sn dbgr     dbg!(&expr)
+            sn dbgt     eprintln!(expr: type = value)
+            sn deref    *expr
+            sn ifmatch  if let pat = expr {}
+            sn match    match expr {}
+            sn ref      &expr
+            sn refm     &mut expr
+            sn return   return expr
+            sn tryintou expr.try_into().unwrap()
+            sn unsafe   unsafe {}
         "#]],
     );
     check_empty(
@@ -1278,17 +1293,20 @@ fn main() {
 }
 "#,
         expect![[r#"
-            me foo()  fn(&self)
-            sn box    Box::new(expr)
-            sn call   function(expr)
-            sn dbg    dbg!(expr)
-            sn dbgr   dbg!(&expr)
-            sn deref  *expr
-            sn match  match expr {}
-            sn ref    &expr
-            sn refm   &mut expr
-            sn return return expr
-            sn unsafe unsafe {}
+            me foo()    fn(&self)
+            sn box      Box::new(expr)
+            sn call     function(expr)
+            sn dbg      dbg!(expr)
+            sn dbgr     dbg!(&expr)
+            sn dbgt     eprintln!(expr: type = value)
+            sn deref    *expr
+            sn ifmatch  if let pat = expr {}
+            sn match    match expr {}
+            sn ref      &expr
+            sn refm     &mut expr
+            sn return   return expr
+            sn tryintou expr.try_into().unwrap()
+            sn unsafe   unsafe {}
         "#]],
     );
     check_empty(
@@ -1304,19 +1322,22 @@ fn main() {
 }
 "#,
         expect![[r#"
-            sn box    Box::new(expr)
-            sn call   function(expr)
-            sn dbg    dbg!(expr)
-            sn dbgr   dbg!(&expr)
-            sn deref  *expr
-            sn if     if expr {}
-            sn match  match expr {}
-            sn not    !expr
-            sn ref    &expr
-            sn refm   &mut expr
-            sn return return expr
-            sn unsafe unsafe {}
-            sn while  while expr {}
+            sn box      Box::new(expr)
+            sn call     function(expr)
+            sn dbg      dbg!(expr)
+            sn dbgr     dbg!(&expr)
+            sn dbgt     eprintln!(expr: type = value)
+            sn deref    *expr
+            sn if       if expr {}
+            sn ifmatch  if let pat = expr {}
+            sn match    match expr {}
+            sn not      !expr
+            sn ref      &expr
+            sn refm     &mut expr
+            sn return   return expr
+            sn tryintou expr.try_into().unwrap()
+            sn unsafe   unsafe {}
+            sn while    while expr {}
         "#]],
     );
 }
@@ -24,19 +24,22 @@ fn main() {
 }
 "#,
         expect![[r#"
-            me foo()  fn(&self)
-            sn box    Box::new(expr)
-            sn call   function(expr)
-            sn dbg    dbg!(expr)
-            sn dbgr   dbg!(&expr)
-            sn deref  *expr
-            sn let    let
-            sn letm   let mut
-            sn match  match expr {}
-            sn ref    &expr
-            sn refm   &mut expr
-            sn return return expr
-            sn unsafe unsafe {}
+            me foo()    fn(&self)
+            sn box      Box::new(expr)
+            sn call     function(expr)
+            sn dbg      dbg!(expr)
+            sn dbgr     dbg!(&expr)
+            sn dbgt     eprintln!(expr: type = value)
+            sn deref    *expr
+            sn ifmatch  if let pat = expr {}
+            sn let      let
+            sn letm     let mut
+            sn match    match expr {}
+            sn ref      &expr
+            sn refm     &mut expr
+            sn return   return expr
+            sn tryintou expr.try_into().unwrap()
+            sn unsafe   unsafe {}
         "#]],
     )
 }
@@ -57,19 +60,22 @@ fn main() {
 }
 "#,
         expect![[r#"
-            me foo()  fn(&self)
-            sn box    Box::new(expr)
-            sn call   function(expr)
-            sn dbg    dbg!(expr)
-            sn dbgr   dbg!(&expr)
-            sn deref  *expr
-            sn let    let
-            sn letm   let mut
-            sn match  match expr {}
-            sn ref    &expr
-            sn refm   &mut expr
-            sn return return expr
-            sn unsafe unsafe {}
+            me foo()    fn(&self)
+            sn box      Box::new(expr)
+            sn call     function(expr)
+            sn dbg      dbg!(expr)
+            sn dbgr     dbg!(&expr)
+            sn dbgt     eprintln!(expr: type = value)
+            sn deref    *expr
+            sn ifmatch  if let pat = expr {}
+            sn let      let
+            sn letm     let mut
+            sn match    match expr {}
+            sn ref      &expr
+            sn refm     &mut expr
+            sn return   return expr
+            sn tryintou expr.try_into().unwrap()
+            sn unsafe   unsafe {}
         "#]],
     )
 }
@@ -92,19 +98,22 @@ fn surprise() {
 fn main() {}
 "#,
         expect![[r#"
-            me foo()  fn(&self)
-            sn box    Box::new(expr)
-            sn call   function(expr)
-            sn dbg    dbg!(expr)
-            sn dbgr   dbg!(&expr)
-            sn deref  *expr
-            sn let    let
-            sn letm   let mut
-            sn match  match expr {}
-            sn ref    &expr
-            sn refm   &mut expr
-            sn return return expr
-            sn unsafe unsafe {}
+            me foo()    fn(&self)
+            sn box      Box::new(expr)
+            sn call     function(expr)
+            sn dbg      dbg!(expr)
+            sn dbgr     dbg!(&expr)
+            sn dbgt     eprintln!(expr: type = value)
+            sn deref    *expr
+            sn ifmatch  if let pat = expr {}
+            sn let      let
+            sn letm     let mut
+            sn match    match expr {}
+            sn ref      &expr
+            sn refm     &mut expr
+            sn return   return expr
+            sn tryintou expr.try_into().unwrap()
+            sn unsafe   unsafe {}
         "#]],
     )
 }
@@ -127,19 +136,22 @@ fn surprise() {
 fn main() {}
 "#,
         expect![[r#"
-            me foo()  fn(&self)
-            sn box    Box::new(expr)
-            sn call   function(expr)
-            sn dbg    dbg!(expr)
-            sn dbgr   dbg!(&expr)
-            sn deref  *expr
-            sn let    let
-            sn letm   let mut
-            sn match  match expr {}
-            sn ref    &expr
-            sn refm   &mut expr
-            sn return return expr
-            sn unsafe unsafe {}
+            me foo()    fn(&self)
+            sn box      Box::new(expr)
+            sn call     function(expr)
+            sn dbg      dbg!(expr)
+            sn dbgr     dbg!(&expr)
+            sn dbgt     eprintln!(expr: type = value)
+            sn deref    *expr
+            sn ifmatch  if let pat = expr {}
+            sn let      let
+            sn letm     let mut
+            sn match    match expr {}
+            sn ref      &expr
+            sn refm     &mut expr
+            sn return   return expr
+            sn tryintou expr.try_into().unwrap()
+            sn unsafe   unsafe {}
         "#]],
     )
 }
@@ -1166,13 +1166,16 @@ fn here_we_go() {
             sn call              function(expr)
             sn dbg               dbg!(expr)
             sn dbgr              dbg!(&expr)
+            sn dbgt              eprintln!(expr: type = value)
             sn deref             *expr
+            sn ifmatch           if let pat = expr {}
             sn let               let
             sn letm              let mut
             sn match             match expr {}
             sn ref               &expr
             sn refm              &mut expr
             sn return            return expr
+            sn tryintou          expr.try_into().unwrap()
             sn unsafe            unsafe {}
         "#]],
     );
@@ -1,7 +1,7 @@
 //! See [`FamousDefs`].
 
 use base_db::{CrateOrigin, LangCrateOrigin, SourceDatabase};
-use hir::{Crate, Enum, Function, Macro, Module, ScopeDef, Semantics, Trait};
+use hir::{Crate, Enum, Function, Macro, Module, ScopeDef, Semantics, Struct, Trait};
 use syntax::ToSmolStr;
 
 use crate::RootDatabase;
@@ -47,6 +47,10 @@ pub fn core_cmp_Ord(&self) -> Option<Trait> {
         self.find_trait("core:cmp:Ord")
     }
 
+    pub fn core_clone_Clone(&self) -> Option<Trait> {
+        self.find_trait("core:clone:Clone")
+    }
+
     pub fn core_convert_From(&self) -> Option<Trait> {
         self.find_trait("core:convert:From")
     }
@@ -79,6 +83,14 @@ pub fn core_iter_IntoIterator(&self) -> Option<Trait> {
         self.find_trait("core:iter:traits:collect:IntoIterator")
     }
 
+    pub fn core_iter_Sum(&self) -> Option<Trait> {
+        self.find_trait("core:iter:Sum")
+    }
+
+    pub fn core_iter_Product(&self) -> Option<Trait> {
+        self.find_trait("core:iter:Product")
+    }
+
     pub fn core_iter(&self) -> Option<Module> {
         self.find_module("core:iter")
     }
@@ -127,6 +139,38 @@ pub fn core_macros_unimplemented(&self) -> Option<Macro> {
         self.find_macro("core:unimplemented")
     }
 
+    pub fn alloc_string_String(&self) -> Option<Struct> {
+        self.find_struct("alloc:string:String")
+    }
+
+    pub fn alloc_vec_Vec(&self) -> Option<Struct> {
+        self.find_struct("alloc:vec:Vec")
+    }
+
+    pub fn alloc_boxed_Box(&self) -> Option<Struct> {
+        self.find_struct("alloc:boxed:Box")
+    }
+
+    pub fn alloc_collections_BTreeMap(&self) -> Option<Struct> {
+        self.find_struct("alloc:collections:BTreeMap")
+    }
+
+    pub fn alloc_collections_btree_map_Entry(&self) -> Option<Enum> {
+        self.find_enum("alloc:collections:btree_map:Entry")
+    }
+
+    pub fn alloc_collections_VecDeque(&self) -> Option<Struct> {
+        self.find_struct("alloc:collections:VecDeque")
+    }
+
+    pub fn std_collections_HashMap(&self) -> Option<Struct> {
+        self.find_struct("std:collections:HashMap")
+    }
+
+    pub fn std_collections_hash_map_Entry(&self) -> Option<Enum> {
+        self.find_enum("std:collections:hash_map:Entry")
+    }
+
     pub fn builtin_crates(&self) -> impl Iterator<Item = Crate> {
         IntoIterator::into_iter([
             self.std(),
@@ -159,6 +203,13 @@ fn find_enum(&self, path: &str) -> Option<Enum> {
         }
     }
 
+    fn find_struct(&self, path: &str) -> Option<Struct> {
+        match self.find_def(path)? {
+            hir::ScopeDef::ModuleDef(hir::ModuleDef::Adt(hir::Adt::Struct(it))) => Some(it),
+            _ => None,
+        }
+    }
+
     fn find_module(&self, path: &str) -> Option<Module> {
         match self.find_def(path)? {
             hir::ScopeDef::ModuleDef(hir::ModuleDef::Module(it)) => Some(it),
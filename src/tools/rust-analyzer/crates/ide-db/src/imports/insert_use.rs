@@ -171,7 +171,7 @@ pub fn clone_for_update(&self) -> Self {
 
 /// Insert an import path into the given file/node. A `merge` value of none indicates that no import merging is allowed to occur.
 pub fn insert_use(scope: &ImportScope, path: ast::Path, cfg: &InsertUseConfig) {
-    insert_use_with_alias_option(scope, path, cfg, None);
+    insert_use_tree(scope, make::use_tree(path, None, None, false), cfg);
 }
 
 pub fn insert_use_as_alias(scope: &ImportScope, path: ast::Path, cfg: &InsertUseConfig) {
@@ -185,16 +185,16 @@ pub fn insert_use_as_alias(scope: &ImportScope, path: ast::Path, cfg: &InsertUse
         .expect("Failed to make ast node `Rename`");
     let alias = node.rename();
 
-    insert_use_with_alias_option(scope, path, cfg, alias);
+    insert_use_tree(scope, make::use_tree(path, None, alias, false), cfg);
 }
 
-fn insert_use_with_alias_option(
-    scope: &ImportScope,
-    path: ast::Path,
-    cfg: &InsertUseConfig,
-    alias: Option<ast::Rename>,
-) {
-    let _p = tracing::info_span!("insert_use_with_alias_option").entered();
+/// Insert a glob import of the module at `path`, as in `use path::*;`.
+pub fn insert_use_glob(scope: &ImportScope, path: ast::Path, cfg: &InsertUseConfig) {
+    insert_use_tree(scope, make::use_tree(path, None, None, true), cfg);
+}
+
+fn insert_use_tree(scope: &ImportScope, mut use_tree: ast::UseTree, cfg: &InsertUseConfig) {
+    let _p = tracing::info_span!("insert_use_tree").entered();
     let mut mb = match cfg.granularity {
         ImportGranularity::Crate => Some(MergeBehavior::Crate),
         ImportGranularity::Module => Some(MergeBehavior::Module),
@@ -214,7 +214,6 @@ fn insert_use_with_alias_option(
         };
     }
 
-    let mut use_tree = make::use_tree(path, None, alias, false);
     if mb == Some(MergeBehavior::One) && use_tree.path().is_some() {
         use_tree = use_tree.clone_for_update();
         use_tree.wrap_in_tree_list();
@@ -56,6 +56,7 @@
 //!     sized:
 //!     slice:
 //!     str:
+//!     sum: iterator
 //!     sync: sized
 //!     transmute:
 //!     try: infallible
@@ -842,6 +843,25 @@ pub trait Eq: PartialEq<Self> {}
     pub macro Eq($item:item) {}
     // endregion:derive
 
+    // region:builtin_impls
+    macro_rules! impl_eq {
+        ($($t:ty)*) => {
+            $(
+                impl PartialEq for $t {
+                    fn eq(&self, other: &$t) -> bool {
+                        loop {}
+                    }
+                }
+                impl Eq for $t {}
+            )*
+        }
+    }
+
+    impl_eq! {
+        usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128
+    }
+    // endregion:builtin_impls
+
     // region:ord
     #[lang = "partial_ord"]
     pub trait PartialOrd<Rhs: ?Sized = Self>: PartialEq<Rhs> {
@@ -865,6 +885,28 @@ pub enum Ordering {
     pub macro Ord($item:item) {}
     // endregion:derive
 
+    // region:builtin_impls
+    macro_rules! impl_ord {
+        ($($t:ty)*) => {
+            $(
+                impl PartialOrd for $t {
+                    fn partial_cmp(&self, other: &$t) -> Option<Ordering> {
+                        loop {}
+                    }
+                }
+                impl Ord for $t {
+                    fn cmp(&self, other: &$t) -> Ordering {
+                        loop {}
+                    }
+                }
+            )*
+        }
+    }
+
+    impl_ord! {
+        usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128
+    }
+    // endregion:builtin_impls
     // endregion:ord
 }
 // endregion:eq
@@ -1364,8 +1406,58 @@ fn next(&mut self) -> Option<T> {
             }
         }
         pub use self::collect::IntoIterator;
+
+        // region:sum
+        mod accum {
+            use super::Iterator;
+
+            pub trait Sum<A = Self>: Sized {
+                fn sum<I: Iterator<Item = A>>(iter: I) -> Self;
+            }
+            pub trait Product<A = Self>: Sized {
+                fn product<I: Iterator<Item = A>>(iter: I) -> Self;
+            }
+
+            // region:builtin_impls
+            macro_rules! impl_sum_product {
+                ($($t:ty)*) => {
+                    $(
+                        impl Sum for $t {
+                            fn sum<I: Iterator<Item = $t>>(iter: I) -> $t {
+                                loop {}
+                            }
+                        }
+                        impl<'a> Sum<&'a $t> for $t {
+                            fn sum<I: Iterator<Item = &'a $t>>(iter: I) -> $t {
+                                loop {}
+                            }
+                        }
+                        impl Product for $t {
+                            fn product<I: Iterator<Item = $t>>(iter: I) -> $t {
+                                loop {}
+                            }
+                        }
+                        impl<'a> Product<&'a $t> for $t {
+                            fn product<I: Iterator<Item = &'a $t>>(iter: I) -> $t {
+                                loop {}
+                            }
+                        }
+                    )*
+                }
+            }
+
+            impl_sum_product! {
+                usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64
+            }
+            // endregion:builtin_impls
+        }
+        pub use self::accum::{Product, Sum};
+        // endregion:sum
     }
     pub use self::traits::{IntoIterator, Iterator};
+    // region:sum
+    pub use self::traits::{Product, Sum};
+    // endregion:sum
 }
 // endregion:iterator
 